@@ -1,5 +1,6 @@
 use crate::helper::{
-    LocationLookup, combine_errors, ensure_snafu_implicit, get_crate_path, lookup_location_field,
+    LocationLookup, combine_errors, ensure_snafu_implicit, has_stack_keyword,
+    lookup_location_field, resolve_crate_path,
 };
 use crate::suzu_attr;
 use proc_macro2::TokenStream;
@@ -7,17 +8,21 @@ use quote::{format_ident, quote};
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
 use syn::token::Colon;
-use syn::{Data, DeriveInput, Error, Field, FieldMutability, Fields, FieldsNamed, Visibility};
+use syn::{
+    Data, DataEnum, DeriveInput, Error, Field, FieldMutability, Fields, FieldsNamed, Visibility,
+};
 
 /// Implementation of `#[suzunari_error]`.
 ///
-/// Three-step pipeline:
+/// Three-step pipeline, after single-field tuple variants are rewritten by
+/// `convert_tuple_variants`:
 /// 1. `process_suzu_attrs` — rewrites `#[suzu(...)]` to `#[snafu(...)]` + `#[stack(...)]`
-/// 2. `resolve_and_inject_location` — ensures every struct/variant has exactly one location field
+/// 2. `resolve_and_inject_location` — ensures every struct/variant has exactly one location
+///    field, except those marked `skip_location`
 /// 3. Emit `#[derive(Debug, Snafu, StackError)]` wrapping the rewritten input
 pub(crate) fn suzunari_error_impl(stream: TokenStream) -> Result<TokenStream, Error> {
     let mut input: DeriveInput = syn::parse2(stream)?;
-    let crate_path = get_crate_path("suzunari-error");
+    let crate_path = resolve_crate_path(&input.attrs);
     // Reject unions early — before process_suzu_attrs, so the error message
     // refers to #[suzunari_error] (the macro the user actually wrote).
     if matches!(input.data, Data::Union(_)) {
@@ -27,19 +32,33 @@ pub(crate) fn suzunari_error_impl(stream: TokenStream) -> Result<TokenStream, Er
         ));
     }
 
+    // Tuple variants become named before #[suzu(...)] processing, so field
+    // keywords like #[suzu(from)] apply to them unchanged.
+    if let Data::Enum(data_enum) = &mut input.data {
+        convert_tuple_variants(data_enum)?;
+    }
+
     // Step 1: Process #[suzu(...)] attrs (from, location, snafu passthrough)
     // - #[suzu(location)] → #[stack(location)] + #[snafu(implicit)]
     // - #[suzu(from)] → DisplayError wrapping + #[snafu(source(from(...)))]
     // - other #[suzu(...)] tokens → #[snafu(...)] passthrough
     suzu_attr::process_suzu_attrs(&mut input, &crate_path)?;
 
-    // Step 2: Resolve and inject location fields
+    // Step 2: Resolve and inject location fields. `skip_location` items are
+    // left as written; derive(StackError) takes their location from the source.
     match &mut input.data {
+        Data::Struct(_) if has_stack_keyword(&input.attrs, "skip_location") => {}
         Data::Struct(data_struct) => match &mut data_struct.fields {
             Fields::Named(fields) => {
                 resolve_and_inject_location(fields, &crate_path)?;
             }
-            _ => {
+            Fields::Unit => {
+                // `struct Foo;` becomes `struct Foo { location: Location }`;
+                // the trailing semicolon is invalid after a braced body.
+                data_struct.fields = location_only_fields(&crate_path);
+                data_struct.semi_token = None;
+            }
+            Fields::Unnamed(_) => {
                 return Err(Error::new(
                     data_struct.fields.span(),
                     "#[suzunari_error] can only be used on structs with named fields",
@@ -49,6 +68,9 @@ pub(crate) fn suzunari_error_impl(stream: TokenStream) -> Result<TokenStream, Er
         Data::Enum(data_enum) => {
             let mut errors = Vec::new();
             for variant in &mut data_enum.variants {
+                if has_stack_keyword(&variant.attrs, "skip_location") {
+                    continue;
+                }
                 match &mut variant.fields {
                     Fields::Named(fields) => {
                         if let Err(e) = resolve_and_inject_location(fields, &crate_path) {
@@ -56,19 +78,10 @@ pub(crate) fn suzunari_error_impl(stream: TokenStream) -> Result<TokenStream, Er
                         }
                     }
                     Fields::Unit => {
-                        let location_field = location_field_impl(&crate_path);
-                        let mut fields = Punctuated::new();
-                        fields.push(location_field);
-                        variant.fields = Fields::Named(FieldsNamed {
-                            brace_token: Default::default(),
-                            named: fields,
-                        });
+                        variant.fields = location_only_fields(&crate_path);
                     }
-                    _ => {
-                        errors.push(Error::new(
-                            variant.fields.span(),
-                            "#[suzunari_error] can only be used on enum variants with named fields",
-                        ));
+                    Fields::Unnamed(_) => {
+                        unreachable!("tuple variants are converted before this point")
                     }
                 }
             }
@@ -92,6 +105,41 @@ pub(crate) fn suzunari_error_impl(stream: TokenStream) -> Result<TokenStream, Er
     })
 }
 
+/// Rewrites each single-field tuple variant `V(T)` into `V { source: T }`.
+///
+/// snafu only supports named-field variants, and the lone field of such a
+/// variant is naturally its source. Field attributes are kept, so
+/// `V(#[suzu(from)] T)` works like `V { #[suzu(from)] source: T }`.
+/// Tuple variants with more than one field are rejected, since there is no
+/// name to give the other fields.
+fn convert_tuple_variants(data_enum: &mut DataEnum) -> Result<(), Error> {
+    let mut errors = Vec::new();
+    for variant in &mut data_enum.variants {
+        let Fields::Unnamed(unnamed) = &mut variant.fields else {
+            continue;
+        };
+        if unnamed.unnamed.len() != 1 {
+            errors.push(Error::new(
+                unnamed.span(),
+                "#[suzunari_error] only supports tuple variants with a single field, which \
+                 becomes the source; use named fields instead",
+            ));
+            continue;
+        }
+        let mut named = Punctuated::new();
+        for mut field in core::mem::take(&mut unnamed.unnamed) {
+            field.ident = Some(format_ident!("source"));
+            field.colon_token = Some(Colon::default());
+            named.push(field);
+        }
+        variant.fields = Fields::Named(FieldsNamed {
+            brace_token: Default::default(),
+            named,
+        });
+    }
+    combine_errors(errors)
+}
+
 /// Location resolution flow for a single struct/variant.
 ///
 /// Delegates the lookup phase (marker check → type heuristic → name conflict)
@@ -102,7 +150,7 @@ fn resolve_and_inject_location(
     fields: &mut FieldsNamed,
     crate_path: &TokenStream,
 ) -> Result<(), Error> {
-    match lookup_location_field(fields, "#[suzu(location)]")? {
+    match lookup_location_field(&fields.named, "#[suzu(location)]")? {
         LocationLookup::Found {
             index,
             needs_stack_attr,
@@ -120,6 +168,17 @@ fn resolve_and_inject_location(
     Ok(())
 }
 
+/// Constructs named fields containing only the synthetic location field.
+/// Used to convert unit structs and unit variants.
+fn location_only_fields(crate_path: &TokenStream) -> Fields {
+    let mut fields = Punctuated::new();
+    fields.push(location_field_impl(crate_path));
+    Fields::Named(FieldsNamed {
+        brace_token: Default::default(),
+        named: fields,
+    })
+}
+
 /// Constructs a synthetic `location: Location` field with
 /// `#[snafu(implicit)]` + `#[stack(location)]`.
 fn location_field_impl(crate_path: &TokenStream) -> Field {
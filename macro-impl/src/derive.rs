@@ -1,29 +1,63 @@
-use crate::helper::{combine_errors, find_location_field, find_source_field, get_crate_path};
-use proc_macro2::{Ident, TokenStream};
-use quote::quote;
+use crate::helper::{
+    ItemAttrs, Rename, combine_errors, find_backtrace_field, find_location_field, find_meta_fields,
+    find_source_field, has_snafu_keyword, looks_like_location_type, parse_item_attrs,
+    resolve_crate_path,
+};
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{quote, quote_spanned};
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
 use syn::token::Comma;
-use syn::{Data, DeriveInput, Error, Fields, FieldsNamed, Generics, Variant};
+use syn::{
+    Data, DeriveInput, Error, Field, Fields, Generics, Index, Member, Type, Variant, WhereClause,
+    parse_quote,
+};
 
 pub(crate) fn stack_error_impl(stream: TokenStream) -> Result<TokenStream, Error> {
     let input: DeriveInput = syn::parse2(stream)?;
     let name = &input.ident;
     let generics = &input.generics;
 
-    let crate_path = get_crate_path("suzunari-error");
+    let mut item = parse_item_attrs(&input.attrs)?;
+    let crate_path = resolve_crate_path(&input.attrs);
+    let rename = item.rename.take();
+    // On a type, `rename = "..."` already sets the whole name (structs) or
+    // the enum portion (enums), so `full` has nothing to override.
+    if let Some(Rename::Full(lit)) = &rename {
+        return Err(Error::new(
+            lit.span(),
+            "`rename(full = ...)` is only valid on enum variants; use `rename = \"...\"` on types",
+        ));
+    }
+    let type_name = rename
+        .map(|r| r.into_lit().value())
+        .unwrap_or_else(|| name.to_string());
 
     match &input.data {
         Data::Struct(data_struct) => match &data_struct.fields {
-            Fields::Named(fields) => Ok(generate_struct_impl(name, fields, &crate_path, generics)?),
-            _ => Err(Error::new(
-                data_struct.fields.span(),
-                "StackError can only be derived for structs with named fields",
+            Fields::Unit if item.skip_location.is_none() => Err(Error::new(
+                input.ident.span(),
+                "StackError cannot be derived for unit structs because they have no Location \
+                 field. Use #[suzunari_error] to inject one",
             )),
+            fields => generate_struct_impl(
+                name,
+                &type_name,
+                &item,
+                has_snafu_keyword(&input.attrs, "transparent"),
+                fields,
+                &crate_path,
+                generics,
+            ),
         },
-        Data::Enum(data_enum) => {
-            generate_enum_impl(name, &data_enum.variants, &crate_path, generics)
-        }
+        Data::Enum(data_enum) => generate_enum_impl(
+            name,
+            &type_name,
+            &item,
+            &data_enum.variants,
+            &crate_path,
+            generics,
+        ),
         Data::Union(_) => Err(Error::new(
             input.ident.span(),
             "StackError cannot be derived for unions",
@@ -31,23 +65,59 @@ pub(crate) fn stack_error_impl(stream: TokenStream) -> Result<TokenStream, Error
     }
 }
 
-/// Generates the StackError implementation for a struct
+/// Generates the StackError implementation for a struct with named or tuple fields.
+///
+/// Tuple structs resolve the location field positionally (e.g., `self.0`).
+/// They never get a `stack_source()` impl: snafu only supports single-field
+/// tuple structs as opaque wrappers, whose only field would be the location.
 fn generate_struct_impl(
     name: &Ident,
-    fields: &FieldsNamed,
+    type_name: &str,
+    item: &ItemAttrs,
+    transparent: bool,
+    fields: &Fields,
     crate_path: &TokenStream,
     generics: &Generics,
 ) -> Result<TokenStream, Error> {
-    let loc_field = find_location_field(fields)?;
-    // find_location_field operates on FieldsNamed, so ident is always Some.
-    let Some(loc_name) = loc_field.ident.as_ref() else {
-        unreachable!("find_location_field operates on FieldsNamed; ident is always present");
-    };
-
-    let type_name_str = name.to_string();
+    let type_name = type_name_expr(type_name, item.qualified.is_some());
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
-    let stack_source_impl = match find_source_field(fields) {
+    let mut relocate_impl = quote! {};
+    let location_expr = match item.skip_location {
+        Some(span) => {
+            let source = skipped_location_source(fields, span)?;
+            let member = field_member(0, source);
+            // Spanned to the source type so a missing `StackError` impl points there.
+            quote_spanned! {source.ty.span()=>
+                #crate_path::StackError::location(&self.#member)
+            }
+        }
+        None => {
+            let (loc_index, loc_field) = find_location_field(fields)?;
+            let loc_member = field_member(loc_index, loc_field);
+            if looks_like_location_type(&loc_field.ty) {
+                let relocate_where_clause = relocate_where_clause(generics);
+                relocate_impl = quote! {
+                    impl #impl_generics #crate_path::RelocateStackError for #name #ty_generics #relocate_where_clause {
+                        fn relocate(mut self, __location: #crate_path::Location) -> Self {
+                            self.#loc_member = __location;
+                            self
+                        }
+                    }
+                };
+            }
+            // Spanned to the field type so a missing `AsLocation` impl points there.
+            quote_spanned! {loc_field.ty.span()=>
+                #crate_path::AsLocation::as_location(&self.#loc_member)
+            }
+        }
+    };
+
+    let source_field = match fields {
+        Fields::Named(named) => find_source_field(named),
+        _ => None,
+    };
+    let stack_source_impl = match source_field {
         Some(field) => {
             // find_source_field operates on FieldsNamed, so ident is always Some.
             let Some(field_name) = field.ident.as_ref() else {
@@ -62,25 +132,94 @@ fn generate_struct_impl(
         None => quote! {},
     };
 
+    let meta_names = field_idents(find_meta_fields(fields)?);
+    let metadata_impl = if meta_names.is_empty() {
+        quote! {}
+    } else {
+        // Spanned to each field so a missing `Display` impl points at the field.
+        let visits = meta_names.iter().map(|meta_name| {
+            quote_spanned! {meta_name.span()=>
+                __visitor(stringify!(#meta_name), &self.#meta_name);
+            }
+        });
+        quote! {
+            fn metadata(&self, __visitor: &mut dyn FnMut(&'static str, &dyn ::core::fmt::Display)) {
+                #(#visits)*
+            }
+        }
+    };
+
+    let backtrace_impl = match find_backtrace_field(fields)? {
+        Some((index, field)) => {
+            let member = field_member(index, field);
+            quote! {
+                fn backtrace(&self) -> Option<&#crate_path::snafu::Backtrace> {
+                    #crate_path::snafu::AsBacktrace::as_backtrace(&self.#member)
+                }
+            }
+        }
+        None => quote! {},
+    };
+
+    let severity_impl = match &item.severity {
+        Some(level) => quote! {
+            fn severity(&self) -> #crate_path::Severity {
+                #crate_path::Severity::#level
+            }
+        },
+        None => quote! {},
+    };
+
+    let transparent_impl = if transparent {
+        quote! {
+            fn is_transparent(&self) -> bool {
+                true
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let error_assertion = error_impl_assertion(name, crate_path, generics);
     let boxed_impl = boxed_stack_error_impl(name, crate_path, generics);
 
     Ok(quote! {
+        #error_assertion
         impl #impl_generics #crate_path::StackError for #name #ty_generics #where_clause {
             fn location(&self) -> #crate_path::Location {
-                self.#loc_name
+                #location_expr
             }
             fn type_name(&self) -> &'static str {
-                #type_name_str
+                #type_name
+            }
+            fn module_path(&self) -> Option<&'static str> {
+                Some(::core::module_path!())
             }
+            #severity_impl
+            #transparent_impl
             #stack_source_impl
+            #metadata_impl
+            #backtrace_impl
         }
+        #relocate_impl
         #boxed_impl
     })
 }
 
-/// Generates the StackError implementation for an enum
+/// Generates the StackError implementation for an enum.
+///
+/// `type_name()` is `Enum::Variant`, where `enum_type_name` is the enum
+/// identifier or its type-level rename. A variant-level `rename` replaces only
+/// the variant portion; `rename(full = ...)` replaces the whole string.
+///
+/// With `qualified`, each name is prefixed with the module path.
+///
+/// `severity()` is the variant's own `severity`, else the type-level one,
+/// else the trait default.
 fn generate_enum_impl(
     name: &Ident,
+    enum_type_name: &str,
+    item: &ItemAttrs,
     variants: &Punctuated<Variant, Comma>,
     crate_path: &TokenStream,
     generics: &Generics,
@@ -90,39 +229,118 @@ fn generate_enum_impl(
     struct VariantInfo<'a> {
         ident: &'a Ident,
         loc_name: &'a Ident,
+        loc_ty: &'a Type,
+        /// `loc_name` is the source field of a `skip_location` variant.
+        loc_from_source: bool,
         source_field_name: Option<&'a Ident>,
+        meta_names: Vec<&'a Ident>,
+        backtrace_name: Option<&'a Ident>,
+        type_name: String,
+        severity: Option<Ident>,
+        transparent: bool,
     }
     let mut variant_infos = Vec::with_capacity(variants.len());
     let mut errors = Vec::new();
     for variant in variants {
-        let Fields::Named(fields) = &variant.fields else {
+        let variant_item = match parse_item_attrs(&variant.attrs) {
+            Ok(item) => item,
+            Err(e) => {
+                errors.push(e);
+                continue;
+            }
+        };
+        if let Some(span) = variant_item.qualified {
+            errors.push(Error::new(
+                span,
+                "`qualified` applies to the whole enum; put it on the enum instead of a variant",
+            ));
+            continue;
+        }
+        if let Some((span, _)) = &variant_item.crate_path {
             errors.push(Error::new(
-                variant.fields.span(),
-                "StackError can only be derived for enums with named fields in all variants",
+                *span,
+                "`crate` applies to the whole enum; put it on the enum instead of a variant",
             ));
             continue;
+        }
+        let type_name = match variant_item.rename {
+            Some(Rename::Full(lit)) => lit.value(),
+            Some(Rename::Name(lit)) => format!("{enum_type_name}::{}", lit.value()),
+            None => format!("{enum_type_name}::{}", variant.ident),
         };
-        let loc_field = match find_location_field(fields) {
-            Ok(field) => field,
+        let severity = variant_item.severity.or_else(|| item.severity.clone());
+        let fields = match &variant.fields {
+            Fields::Named(fields) => fields,
+            // A derive cannot add fields, so a unit variant has nowhere to
+            // store its location. #[suzunari_error] rewrites it before derive runs.
+            Fields::Unit => {
+                if let Some(span) = variant_item.skip_location {
+                    errors.push(Error::new(span, SKIP_LOCATION_WITHOUT_SOURCE));
+                    continue;
+                }
+                errors.push(Error::new(
+                    variant.ident.span(),
+                    format!(
+                        "unit variant `{}` has no Location field. Add one, or use \
+                         #[suzunari_error] to inject it",
+                        variant.ident
+                    ),
+                ));
+                continue;
+            }
+            Fields::Unnamed(_) => {
+                errors.push(Error::new(
+                    variant.fields.span(),
+                    "StackError can only be derived for enums with named fields in all variants",
+                ));
+                continue;
+            }
+        };
+        let located = match variant_item.skip_location {
+            Some(span) => skipped_location_source(&variant.fields, span).map(|f| (f, true)),
+            None => find_location_field(&variant.fields).map(|(_, f)| (f, false)),
+        };
+        let (loc_field, loc_from_source) = match located {
+            Ok(located) => located,
             Err(e) => {
                 errors.push(e);
                 continue;
             }
         };
-        // find_location_field operates on FieldsNamed, so ident is always Some.
+        // Both lookups operate on FieldsNamed here, so ident is always Some.
         let Some(loc_name) = loc_field.ident.as_ref() else {
-            unreachable!("find_location_field operates on FieldsNamed; ident is always present");
+            unreachable!("location lookups on FieldsNamed always return named fields");
         };
         let source_field_name = find_source_field(fields).and_then(|f| f.ident.as_ref());
+        let meta_names = match find_meta_fields(&variant.fields) {
+            Ok(meta_fields) => field_idents(meta_fields),
+            Err(e) => {
+                errors.push(e);
+                continue;
+            }
+        };
+        let backtrace_name = match find_backtrace_field(&variant.fields) {
+            Ok(field) => field.and_then(|(_, f)| f.ident.as_ref()),
+            Err(e) => {
+                errors.push(e);
+                continue;
+            }
+        };
         variant_infos.push(VariantInfo {
             ident: &variant.ident,
             loc_name,
+            loc_ty: &loc_field.ty,
+            loc_from_source,
             source_field_name,
+            meta_names,
+            backtrace_name,
+            type_name,
+            severity,
+            transparent: has_snafu_keyword(&variant.attrs, "transparent"),
         });
     }
     combine_errors(errors)?;
 
-    let enum_name_str = name.to_string();
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
     let has_any_source = variant_infos.iter().any(|v| v.source_field_name.is_some());
@@ -130,13 +348,22 @@ fn generate_enum_impl(
     let location_match_arms = variant_infos.iter().map(|v| {
         let variant_name = v.ident;
         let loc_name = v.loc_name;
-        quote! { #name::#variant_name { #loc_name, .. } => #loc_name, }
+        let location_expr = if v.loc_from_source {
+            quote_spanned! {v.loc_ty.span()=>
+                #crate_path::StackError::location(#loc_name)
+            }
+        } else {
+            quote_spanned! {v.loc_ty.span()=>
+                #crate_path::AsLocation::as_location(#loc_name)
+            }
+        };
+        quote! { #name::#variant_name { #loc_name, .. } => #location_expr, }
     });
 
     let type_name_match_arms = variant_infos.iter().map(|v| {
         let variant_name = v.ident;
-        let full_name = format!("{enum_name_str}::{variant_name}");
-        quote! { #name::#variant_name { .. } => #full_name, }
+        let type_name = type_name_expr(&v.type_name, item.qualified.is_some());
+        quote! { #name::#variant_name { .. } => #type_name, }
     });
 
     let stack_source_match_arms = variant_infos.iter().map(|v| {
@@ -165,9 +392,137 @@ fn generate_enum_impl(
         quote! {}
     };
 
+    let has_any_meta = variant_infos.iter().any(|v| !v.meta_names.is_empty());
+
+    let metadata_match_arms = variant_infos.iter().map(|v| {
+        let variant_name = v.ident;
+        let meta_names = &v.meta_names;
+        let visits = meta_names.iter().map(|meta_name| {
+            quote_spanned! {meta_name.span()=>
+                __visitor(stringify!(#meta_name), #meta_name);
+            }
+        });
+        quote! {
+            #name::#variant_name { #(#meta_names,)* .. } => {
+                #(#visits)*
+            }
+        }
+    });
+
+    let metadata_impl = if has_any_meta {
+        quote! {
+            fn metadata(&self, __visitor: &mut dyn FnMut(&'static str, &dyn ::core::fmt::Display)) {
+                match self {
+                    #(#metadata_match_arms)*
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let has_any_backtrace = variant_infos.iter().any(|v| v.backtrace_name.is_some());
+
+    let backtrace_match_arms = variant_infos.iter().map(|v| {
+        let variant_name = v.ident;
+        match v.backtrace_name {
+            Some(field_name) => quote! {
+                #name::#variant_name { #field_name, .. } => {
+                    #crate_path::snafu::AsBacktrace::as_backtrace(#field_name)
+                }
+            },
+            None => quote! {
+                #name::#variant_name { .. } => None,
+            },
+        }
+    });
+
+    let backtrace_impl = if has_any_backtrace {
+        quote! {
+            fn backtrace(&self) -> Option<&#crate_path::snafu::Backtrace> {
+                match self {
+                    #(#backtrace_match_arms)*
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let has_any_severity = variant_infos.iter().any(|v| v.severity.is_some());
+
+    let severity_match_arms = variant_infos.iter().map(|v| {
+        let variant_name = v.ident;
+        match &v.severity {
+            Some(level) => quote! {
+                #name::#variant_name { .. } => #crate_path::Severity::#level,
+            },
+            None => quote! {
+                #name::#variant_name { .. } => ::core::default::Default::default(),
+            },
+        }
+    });
+
+    let severity_impl = if has_any_severity {
+        quote! {
+            fn severity(&self) -> #crate_path::Severity {
+                match self {
+                    #(#severity_match_arms)*
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    // Only transparent variants get an arm; the rest fall through to `false`.
+    let transparent_variants: Vec<_> = variant_infos
+        .iter()
+        .filter(|v| v.transparent)
+        .map(|v| v.ident)
+        .collect();
+    let transparent_impl = if transparent_variants.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            fn is_transparent(&self) -> bool {
+                matches!(self, #(#name::#transparent_variants { .. })|*)
+            }
+        }
+    };
+
+    // All-or-nothing: one variant without a plain `Location` field leaves
+    // nowhere to store the new location.
+    let relocatable = !variant_infos.is_empty()
+        && variant_infos
+            .iter()
+            .all(|v| !v.loc_from_source && looks_like_location_type(v.loc_ty));
+    let relocate_impl = if relocatable {
+        let relocate_match_arms = variant_infos.iter().map(|v| {
+            let variant_name = v.ident;
+            let loc_name = v.loc_name;
+            quote! { #name::#variant_name { #loc_name: __field, .. } => *__field = __location, }
+        });
+        let relocate_where_clause = relocate_where_clause(generics);
+        quote! {
+            impl #impl_generics #crate_path::RelocateStackError for #name #ty_generics #relocate_where_clause {
+                fn relocate(mut self, __location: #crate_path::Location) -> Self {
+                    match &mut self {
+                        #(#relocate_match_arms)*
+                    }
+                    self
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let error_assertion = error_impl_assertion(name, crate_path, generics);
     let boxed_impl = boxed_stack_error_impl(name, crate_path, generics);
 
     Ok(quote! {
+        #error_assertion
         impl #impl_generics #crate_path::StackError for #name #ty_generics #where_clause {
             fn location(&self) -> #crate_path::Location {
                 match self {
@@ -179,12 +534,129 @@ fn generate_enum_impl(
                     #(#type_name_match_arms)*
                 }
             }
+            fn module_path(&self) -> Option<&'static str> {
+                Some(::core::module_path!())
+            }
+            #severity_impl
+            #transparent_impl
             #stack_source_impl
+            #metadata_impl
+            #backtrace_impl
         }
+        #relocate_impl
         #boxed_impl
     })
 }
 
+/// Returns the `type_name()` expression, prefixed with the defining module's
+/// path at expansion time when `qualified` is set.
+fn type_name_expr(type_name: &str, qualified: bool) -> TokenStream {
+    if qualified {
+        quote! { ::core::concat!(::core::module_path!(), "::", #type_name) }
+    } else {
+        quote! { #type_name }
+    }
+}
+
+const SKIP_LOCATION_WITHOUT_SOURCE: &str =
+    "`skip_location` takes the location from the source, but there is no source field";
+
+/// Returns the source field that supplies `location()` for an item marked
+/// `skip_location` (at `span`).
+///
+/// The item must not have a location field of its own, and its source must
+/// be a named field; tuple structs have no way to mark one.
+fn skipped_location_source(fields: &Fields, span: Span) -> Result<&Field, Error> {
+    if let Ok((_, field)) = find_location_field(fields) {
+        let mut err = Error::new(
+            span,
+            "`skip_location` conflicts with the location field; remove one of them",
+        );
+        let field_span = field
+            .ident
+            .as_ref()
+            .map_or_else(|| field.ty.span(), Ident::span);
+        err.combine(Error::new(field_span, "location field is here"));
+        return Err(err);
+    }
+    match fields {
+        Fields::Named(named) => find_source_field(named),
+        _ => None,
+    }
+    .ok_or_else(|| Error::new(span, SKIP_LOCATION_WITHOUT_SOURCE))
+}
+
+/// Returns the expression member (`self.name` or `self.0`) for a field.
+fn field_member(index: usize, field: &Field) -> Member {
+    match &field.ident {
+        Some(ident) => Member::Named(ident.clone()),
+        None => Member::Unnamed(Index::from(index)),
+    }
+}
+
+/// Collects the identifiers of named fields.
+fn field_idents(fields: Vec<&Field>) -> Vec<&Ident> {
+    // Callers pass fields from FieldsNamed, so ident is always Some.
+    fields
+        .into_iter()
+        .filter_map(|f| f.ident.as_ref())
+        .collect()
+}
+
+/// Generates a check that `name` implements `Error`, which `StackError`
+/// requires and which `derive(StackError)` does not provide.
+///
+/// The unsatisfied supertrait is reported anyway; this adds an error that
+/// names the likely fix (`#[derive(Snafu)]`), via the
+/// `#[diagnostic::on_unimplemented]` message on `__private::ErrorImplemented`.
+///
+/// For a non-generic type the check is a `where` bound, which rustc reports
+/// with the item-level errors, so it comes first. A bound on generic
+/// parameters is assumed rather than checked, so generic types are checked
+/// by a call in a function body instead.
+fn error_impl_assertion(
+    name: &Ident,
+    crate_path: &TokenStream,
+    generics: &Generics,
+) -> TokenStream {
+    if generics.params.is_empty() {
+        return quote! {
+            const _: () = {
+                fn __assert_error_implemented()
+                where
+                    #name: #crate_path::__private::ErrorImplemented,
+                {
+                }
+            };
+        };
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    quote! {
+        const _: () = {
+            fn __assert_error_implemented #impl_generics () #where_clause {
+                #crate_path::__private::assert_error_implemented::<#name #ty_generics>();
+            }
+        };
+    }
+}
+
+/// Returns the `where` clause of the `RelocateStackError` impl: the item's
+/// own predicates plus `Self: Error`.
+///
+/// Without `Error`, the `StackError` supertrait of `RelocateStackError` is
+/// unsatisfied and would be reported once more, after the
+/// [`error_impl_assertion`] error. The bound is higher-ranked so that rustc
+/// does not reject it as trivially false for a non-generic type; the impl
+/// then simply does not apply.
+fn relocate_where_clause(generics: &Generics) -> WhereClause {
+    let mut generics = generics.clone();
+    let where_clause = generics.make_where_clause();
+    where_clause
+        .predicates
+        .push(parse_quote!(for<'__suzunari> Self: ::core::error::Error));
+    where_clause.clone()
+}
+
 /// Generates `From<T> for BoxedStackError` only when the alloc feature is enabled.
 ///
 /// Uses `cfg!(feature = "alloc")` on the proc-macro crate's own feature flag,
@@ -3,7 +3,10 @@ use quote::{format_ident, quote};
 use syn::ext::IdentExt;
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
-use syn::{Error, Field, FieldsNamed, GenericArgument, Meta, PathArguments, Type};
+use syn::{
+    Attribute, Error, Expr, ExprLit, Field, Fields, FieldsNamed, GenericArgument, Lit, LitStr,
+    Meta, MetaNameValue, Path, PathArguments, Type,
+};
 
 /// Returns a token stream for the absolute crate path (e.g., `::suzunari_error`).
 ///
@@ -28,7 +31,10 @@ pub(crate) enum LocationLookup {
     NotFound,
 }
 
-/// Resolves the location field in a struct/variant's named fields.
+/// Resolves the location field in a struct/variant's fields.
+///
+/// Works on both named and unnamed (tuple) fields; the name conflict check
+/// only applies to named fields.
 ///
 /// Resolution order:
 /// 1. `#[stack(location)]` marker — highest priority, any field name
@@ -39,12 +45,12 @@ pub(crate) enum LocationLookup {
 /// the context-appropriate attribute (e.g., `"#[suzu(location)]"` for
 /// `#[suzunari_error]`, `"#[stack(location)]"` for `derive(StackError)`).
 pub(crate) fn lookup_location_field(
-    fields: &FieldsNamed,
+    fields: &Punctuated<Field, syn::Token![,]>,
     location_attr_hint: &str,
 ) -> Result<LocationLookup, Error> {
     // 1. Check #[stack(location)] markers
     let mut marked: Vec<(usize, Span)> = Vec::new();
-    for (i, field) in fields.named.iter().enumerate() {
+    for (i, field) in fields.iter().enumerate() {
         if let Some(attr_span) = has_stack_location_attr(field)? {
             marked.push((i, attr_span));
         }
@@ -72,7 +78,6 @@ pub(crate) fn lookup_location_field(
 
     // 2. Check Location-typed fields
     let location_typed: Vec<(usize, Span)> = fields
-        .named
         .iter()
         .enumerate()
         .filter(|(_, f)| looks_like_location_type(&f.ty))
@@ -106,7 +111,6 @@ pub(crate) fn lookup_location_field(
 
     // 3. Name conflict: field named "location" with the wrong type
     if let Some(field) = fields
-        .named
         .iter()
         .find(|f| f.ident.as_ref().is_some_and(|i| i == "location"))
     {
@@ -122,23 +126,32 @@ pub(crate) fn lookup_location_field(
     Ok(LocationLookup::NotFound)
 }
 
-/// Finds the location field in a struct/variant's named fields.
+/// Finds the location field in a struct/variant's named or tuple fields.
 ///
 /// Delegates to [`lookup_location_field`] for resolution, then returns the
-/// field reference or an error if not found.
+/// field's position and reference, or an error if not found. Unit fields
+/// never contain a location field.
 ///
 /// Used by `derive(StackError)` to resolve the location field dynamically.
-pub(crate) fn find_location_field(fields: &FieldsNamed) -> Result<&Field, Error> {
-    match lookup_location_field(fields, "#[stack(location)]")? {
+pub(crate) fn find_location_field(fields: &Fields) -> Result<(usize, &Field), Error> {
+    let lookup = match fields {
+        Fields::Named(named) => lookup_location_field(&named.named, "#[stack(location)]")?,
+        Fields::Unnamed(unnamed) => lookup_location_field(&unnamed.unnamed, "#[stack(location)]")?,
+        Fields::Unit => LocationLookup::NotFound,
+    };
+    match lookup {
         LocationLookup::Found { index, .. } => {
-            let field = &fields.named[index];
-            if !looks_like_location_type(&field.ty) {
+            let Some(field) = fields.iter().nth(index) else {
+                unreachable!("lookup_location_field returns an index within fields");
+            };
+            if is_non_location_type(&field.ty) {
                 return Err(Error::new(
                     field.ty.span(),
-                    "#[stack(location)] field must be of type `suzunari_error::Location`",
+                    "#[stack(location)] field must be of type `suzunari_error::Location` or \
+                     implement `suzunari_error::AsLocation`",
                 ));
             }
-            Ok(field)
+            Ok((index, field))
         }
         LocationLookup::NotFound => Err(Error::new(
             fields.span(),
@@ -148,16 +161,26 @@ pub(crate) fn find_location_field(fields: &FieldsNamed) -> Result<&Field, Error>
     }
 }
 
+/// Keywords accepted inside `#[stack(...)]`.
+const STACK_KEYWORDS: &[&str] = &["location", "meta", "backtrace"];
+
 /// Returns the span of the `#[stack(location)]` attribute if present.
 ///
 /// Returns `Ok(Some(span))` if `#[stack(location)]` is found, `Ok(None)` if not.
 /// The span points to the `#[stack(...)]` attribute itself, enabling precise
 /// error messages when multiple fields have this marker.
+pub(crate) fn has_stack_location_attr(field: &Field) -> Result<Option<Span>, Error> {
+    find_stack_keyword(field, "location")
+}
+
+/// Returns the span of the `#[stack(...)]` attribute containing `keyword`.
 ///
 /// Unlike `is_source_field` (which defers parse errors to snafu), this function
 /// propagates parse errors because `#[stack(...)]` is consumed by our own
-/// `derive(StackError)` — no other macro will report the error.
-pub(crate) fn has_stack_location_attr(field: &Field) -> Result<Option<Span>, Error> {
+/// `derive(StackError)` — no other macro will report the error. Every
+/// `#[stack(...)]` on the field is validated, not only the ones containing
+/// `keyword`.
+fn find_stack_keyword(field: &Field, keyword: &str) -> Result<Option<Span>, Error> {
     let mut found: Option<Span> = None;
     for attr in field.attrs.iter().filter(|a| a.path().is_ident("stack")) {
         let Meta::List(meta_list) = &attr.meta else {
@@ -176,31 +199,36 @@ pub(crate) fn has_stack_location_attr(field: &Field) -> Result<Option<Span>, Err
                 "#[stack()] requires arguments, e.g., #[stack(location)]",
             ));
         }
-        // Reject unknown tokens — only `location` is supported.
-        if let Some(unknown) = nested
-            .iter()
-            .find(|meta| !matches!(meta, Meta::Path(p) if p.is_ident("location")))
-        {
+        // Reject unknown tokens — only bare STACK_KEYWORDS are supported.
+        if let Some(unknown) = nested.iter().find(
+            |meta| !matches!(meta, Meta::Path(p) if STACK_KEYWORDS.iter().any(|k| p.is_ident(k))),
+        ) {
             return Err(Error::new(
                 unknown.span(),
-                "unknown #[stack(...)] argument; only `location` is supported",
+                "unknown #[stack(...)] argument; only `location`, `meta`, and `backtrace` are supported",
             ));
         }
-        // Reject duplicate `location` within the same #[stack(...)] attribute.
-        if nested.len() > 1 {
+        // Reject duplicate keywords within the same #[stack(...)] attribute.
+        // All entries are bare keyword idents at this point.
+        let idents: Vec<Option<&Ident>> = nested.iter().map(|m| m.path().get_ident()).collect();
+        if let Some(dup) = (1..idents.len()).find(|&i| idents[..i].contains(&idents[i])) {
+            let name = idents[dup].map(Ident::to_string).unwrap_or_default();
             return Err(Error::new(
-                nested[1].span(),
-                "duplicate `location` in #[stack(...)]; specify it only once",
+                nested[dup].span(),
+                format!("duplicate `{name}` in #[stack(...)]; specify it only once"),
             ));
         }
+        if !nested.iter().any(|meta| meta.path().is_ident(keyword)) {
+            continue;
+        }
         if let Some(prev_span) = found {
             let mut err = Error::new(
                 attr.span(),
-                "duplicate #[stack(location)] on the same field; specify it only once",
+                format!("duplicate #[stack({keyword})] on the same field; specify it only once"),
             );
             err.combine(Error::new(
                 prev_span,
-                "first occurrence of #[stack(location)] is here",
+                format!("first occurrence of #[stack({keyword})] is here"),
             ));
             return Err(err);
         }
@@ -209,6 +237,282 @@ pub(crate) fn has_stack_location_attr(field: &Field) -> Result<Option<Span>, Err
     Ok(found)
 }
 
+/// Finds the metadata fields (marked with `#[stack(meta)]`) in declaration order.
+///
+/// The field name is used as the metadata key, so `#[stack(meta)]` on a
+/// tuple field is an error.
+pub(crate) fn find_meta_fields(fields: &Fields) -> Result<Vec<&Field>, Error> {
+    let mut meta_fields = Vec::new();
+    for field in fields {
+        let Some(attr_span) = find_stack_keyword(field, "meta")? else {
+            continue;
+        };
+        if field.ident.is_none() {
+            return Err(Error::new(
+                attr_span,
+                "#[stack(meta)] requires a named field; the field name is the metadata key",
+            ));
+        }
+        meta_fields.push(field);
+    }
+    Ok(meta_fields)
+}
+
+/// Finds the backtrace field: the one marked with `#[stack(backtrace)]`, or
+/// else a named field called `backtrace` (snafu's convention).
+///
+/// Without the `backtrace` feature, `StackError::backtrace()` does not exist,
+/// so a marked field is an error and unmarked fields are ignored.
+pub(crate) fn find_backtrace_field(fields: &Fields) -> Result<Option<(usize, &Field)>, Error> {
+    let mut marked: Option<(usize, &Field, Span)> = None;
+    for (index, field) in fields.iter().enumerate() {
+        let Some(attr_span) = find_stack_keyword(field, "backtrace")? else {
+            continue;
+        };
+        if !cfg!(feature = "backtrace") {
+            return Err(Error::new(
+                attr_span,
+                "#[stack(backtrace)] requires the `backtrace` feature of suzunari-error",
+            ));
+        }
+        if let Some((_, _, first_span)) = marked {
+            let mut err = Error::new(
+                attr_span,
+                "multiple #[stack(backtrace)] fields; only one is allowed",
+            );
+            err.combine(Error::new(
+                first_span,
+                "first occurrence of #[stack(backtrace)] is here",
+            ));
+            return Err(err);
+        }
+        marked = Some((index, field, attr_span));
+    }
+    if !cfg!(feature = "backtrace") {
+        return Ok(None);
+    }
+    if let Some((index, field, _)) = marked {
+        return Ok(Some((index, field)));
+    }
+    Ok(fields
+        .iter()
+        .enumerate()
+        .find(|(_, f)| f.ident.as_ref().is_some_and(|i| i == "backtrace")))
+}
+
+/// A `type_name()` override from `#[stack(rename ...)]` on a type or variant.
+pub(crate) enum Rename {
+    /// `rename = "..."` — replaces the type name, or only the variant portion
+    /// of `Enum::Variant`.
+    Name(LitStr),
+    /// `rename(full = "...")` — replaces the whole `Enum::Variant` string.
+    /// Only valid on enum variants.
+    Full(LitStr),
+}
+
+impl Rename {
+    /// Returns the renamed string literal.
+    pub(crate) fn into_lit(self) -> LitStr {
+        match self {
+            Rename::Name(lit) | Rename::Full(lit) => lit,
+        }
+    }
+}
+
+/// `#[stack(...)]` settings on a type or variant.
+#[derive(Default)]
+pub(crate) struct ItemAttrs {
+    /// `rename` override for `type_name()`.
+    pub(crate) rename: Option<Rename>,
+    /// `severity` level, as the `Severity` variant ident (e.g., `Critical`).
+    pub(crate) severity: Option<Ident>,
+    /// Span of `qualified`, which prefixes `type_name()` with the module path.
+    pub(crate) qualified: Option<Span>,
+    /// `crate = path` override for the suzunari-error crate root, with the
+    /// span of the `crate` keyword.
+    pub(crate) crate_path: Option<(Span, Path)>,
+    /// Span of `skip_location`, which takes `location()` from the source
+    /// instead of an own location field.
+    pub(crate) skip_location: Option<Span>,
+}
+
+/// `severity` keywords and the `Severity` variants they map to.
+const SEVERITY_LEVELS: &[(&str, &str)] = &[
+    ("info", "Info"),
+    ("warning", "Warning"),
+    ("error", "Error"),
+    ("critical", "Critical"),
+];
+
+/// Parses the `#[stack(...)]` attributes on a type or variant.
+///
+/// Only `rename`, `severity`, `qualified`, `crate`, and `skip_location` are
+/// accepted at this level, each at most once.
+pub(crate) fn parse_item_attrs(attrs: &[Attribute]) -> Result<ItemAttrs, Error> {
+    let mut item = ItemAttrs::default();
+    let mut first_rename: Option<Span> = None;
+    let mut first_severity: Option<Span> = None;
+    let mut first_crate: Option<Span> = None;
+    for attr in attrs.iter().filter(|a| a.path().is_ident("stack")) {
+        let Meta::List(meta_list) = &attr.meta else {
+            return Err(Error::new(
+                attr.span(),
+                "#[stack] requires arguments, e.g., #[stack(rename = \"...\")]",
+            ));
+        };
+        let nested =
+            meta_list.parse_args_with(Punctuated::<Meta, syn::Token![,]>::parse_terminated)?;
+        if nested.is_empty() {
+            return Err(Error::new(
+                attr.span(),
+                "#[stack()] requires arguments, e.g., #[stack(rename = \"...\")]",
+            ));
+        }
+        for meta in &nested {
+            let (name, first_span) = if meta.path().is_ident("rename") {
+                item.rename = Some(parse_rename(meta)?);
+                ("rename", &mut first_rename)
+            } else if meta.path().is_ident("severity") {
+                item.severity = Some(parse_severity(meta)?);
+                ("severity", &mut first_severity)
+            } else if meta.path().is_ident("qualified") {
+                if !matches!(meta, Meta::Path(_)) {
+                    return Err(Error::new(
+                        meta.span(),
+                        "`qualified` does not accept arguments; use it as a bare keyword",
+                    ));
+                }
+                ("qualified", &mut item.qualified)
+            } else if meta.path().is_ident("skip_location") {
+                if !matches!(meta, Meta::Path(_)) {
+                    return Err(Error::new(
+                        meta.span(),
+                        "`skip_location` does not accept arguments; use it as a bare keyword",
+                    ));
+                }
+                ("skip_location", &mut item.skip_location)
+            } else if meta.path().is_ident("crate") {
+                item.crate_path = Some((meta.path().span(), parse_crate_path(meta)?));
+                ("crate", &mut first_crate)
+            } else {
+                return Err(Error::new(
+                    meta.span(),
+                    "unknown #[stack(...)] argument on a type or variant; only `rename`, \
+                     `severity`, `qualified`, `crate`, and `skip_location` are supported",
+                ));
+            };
+            if let Some(first_span) = *first_span {
+                let mut err = Error::new(
+                    meta.span(),
+                    format!("duplicate `{name}`; specify it only once"),
+                );
+                err.combine(Error::new(
+                    first_span,
+                    format!("first occurrence of `{name}` is here"),
+                ));
+                return Err(err);
+            }
+            *first_span = Some(meta.span());
+        }
+    }
+    Ok(item)
+}
+
+/// Parses `crate = path` into the path of the suzunari-error crate root.
+fn parse_crate_path(meta: &Meta) -> Result<Path, Error> {
+    const USAGE: &str = "expected `crate = path::to::suzunari_error`";
+    let Meta::NameValue(nv) = meta else {
+        return Err(Error::new(meta.span(), USAGE));
+    };
+    match &nv.value {
+        Expr::Path(path) if path.qself.is_none() && path.attrs.is_empty() => Ok(path.path.clone()),
+        value => Err(Error::new(value.span(), USAGE)),
+    }
+}
+
+/// Returns the crate root path for generated code: the type-level
+/// `crate = path` from `#[suzu(...)]` or `#[stack(...)]` if present,
+/// otherwise `::suzunari_error`.
+///
+/// `#[suzunari_error]` needs the path before its `#[suzu(...)]` attributes
+/// are rewritten, so this only looks for the keyword; malformed values are
+/// reported by `derive(StackError)` via [`parse_item_attrs`].
+pub(crate) fn resolve_crate_path(attrs: &[Attribute]) -> TokenStream {
+    let crate_path = attrs
+        .iter()
+        .filter(|a| a.path().is_ident("suzu") || a.path().is_ident("stack"))
+        .filter_map(|a| {
+            a.parse_args_with(Punctuated::<Meta, syn::Token![,]>::parse_terminated)
+                .ok()
+        })
+        .flatten()
+        .find(|meta| meta.path().is_ident("crate"))
+        .and_then(|meta| parse_crate_path(&meta).ok());
+    match crate_path {
+        Some(path) => quote! { #path },
+        None => get_crate_path("suzunari-error"),
+    }
+}
+
+/// Parses `severity = <level>` into the matching `Severity` variant ident.
+fn parse_severity(meta: &Meta) -> Result<Ident, Error> {
+    const USAGE: &str = "expected `severity = info`, `severity = warning`, `severity = error`, or `severity = critical`";
+    let Meta::NameValue(nv) = meta else {
+        return Err(Error::new(meta.span(), USAGE));
+    };
+    let Expr::Path(path) = &nv.value else {
+        return Err(Error::new(nv.value.span(), USAGE));
+    };
+    let level = path
+        .path
+        .get_ident()
+        .and_then(|ident| {
+            SEVERITY_LEVELS
+                .iter()
+                .find(|(keyword, _)| ident == keyword)
+                .map(|(_, variant)| Ident::new(variant, ident.span()))
+        })
+        .ok_or_else(|| Error::new(nv.value.span(), USAGE))?;
+    Ok(level)
+}
+
+/// Parses `rename = "..."` or `rename(full = "...")`.
+fn parse_rename(meta: &Meta) -> Result<Rename, Error> {
+    const USAGE: &str = "expected `rename = \"...\"` or `rename(full = \"...\")`";
+    let rename = match meta {
+        Meta::NameValue(nv) => Rename::Name(
+            string_literal(&nv.value).ok_or_else(|| Error::new(nv.value.span(), USAGE))?,
+        ),
+        Meta::List(list) => {
+            let inner: MetaNameValue =
+                list.parse_args().map_err(|e| Error::new(e.span(), USAGE))?;
+            if !inner.path.is_ident("full") {
+                return Err(Error::new(inner.path.span(), USAGE));
+            }
+            Rename::Full(
+                string_literal(&inner.value)
+                    .ok_or_else(|| Error::new(inner.value.span(), USAGE))?,
+            )
+        }
+        Meta::Path(_) => return Err(Error::new(meta.span(), USAGE)),
+    };
+    let (Rename::Name(lit) | Rename::Full(lit)) = &rename;
+    if lit.value().is_empty() {
+        return Err(Error::new(lit.span(), "`rename` requires a non-empty name"));
+    }
+    Ok(rename)
+}
+
+/// Returns the string literal of `expr`, if it is one.
+fn string_literal(expr: &Expr) -> Option<LitStr> {
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(lit), ..
+        }) => Some(lit.clone()),
+        _ => None,
+    }
+}
+
 /// Extracts the inner type `T` from `DisplayError<T>`.
 ///
 /// Returns `Some(&T)` if the type's last path segment is `DisplayError` with
@@ -252,6 +556,32 @@ pub(crate) fn looks_like_location_type(ty: &Type) -> bool {
     }
 }
 
+/// Returns true for types that can never be location fields: primitives,
+/// `str`, and `String`, including references to them.
+///
+/// Any other type may implement `AsLocation` + `GenerateImplicitData` and is
+/// left to the generated bounds. Rejecting these common mistakes up front
+/// gives one error on the field type instead of unsatisfied trait bounds
+/// spanning the whole macro invocation.
+pub(crate) fn is_non_location_type(ty: &Type) -> bool {
+    const NON_LOCATION_TYPES: &[&str] = &[
+        "String", "str", "bool", "char", "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16",
+        "i32", "i64", "i128", "isize", "f32", "f64",
+    ];
+    match ty {
+        Type::Path(p) => {
+            p.qself.is_none()
+                && p.path.segments.last().is_some_and(|s| {
+                    s.arguments.is_none() && NON_LOCATION_TYPES.iter().any(|name| s.ident == name)
+                })
+        }
+        Type::Reference(r) => is_non_location_type(&r.elem),
+        Type::Paren(p) => is_non_location_type(&p.elem),
+        Type::Tuple(_) | Type::Array(_) | Type::Slice(_) => true,
+        _ => false,
+    }
+}
+
 /// Finds the source field in a struct/variant's named fields.
 ///
 /// A field is considered a source if:
@@ -327,6 +657,40 @@ pub(crate) fn has_snafu_keyword(attrs: &[syn::Attribute], keyword: &str) -> bool
     })
 }
 
+/// Checks if any `#[stack(...)]` attribute contains `keyword` as a top-level
+/// keyword (e.g., `location`, `meta`).
+///
+/// Best-effort like [`has_snafu_keyword`]; validation of `#[stack(...)]`
+/// happens later in `derive(StackError)`.
+pub(crate) fn has_stack_keyword(attrs: &[syn::Attribute], keyword: &str) -> bool {
+    attrs.iter().any(|attr| {
+        if !attr.path().is_ident("stack") {
+            return false;
+        }
+        let Meta::List(meta_list) = &attr.meta else {
+            return false;
+        };
+        snafu_tokens_contain_keyword(&meta_list.tokens, keyword)
+    })
+}
+
+/// Checks if any `#[suzu(...)]` attribute contains `keyword` as a top-level
+/// keyword, before `#[suzu(...)]` is rewritten.
+///
+/// Best-effort like [`has_snafu_keyword`]; validation of `#[suzu(...)]`
+/// happens in `process_suzu_attrs`.
+pub(crate) fn has_suzu_keyword(attrs: &[syn::Attribute], keyword: &str) -> bool {
+    attrs.iter().any(|attr| {
+        if !attr.path().is_ident("suzu") {
+            return false;
+        }
+        let Meta::List(meta_list) = &attr.meta else {
+            return false;
+        };
+        snafu_tokens_contain_keyword(&meta_list.tokens, keyword)
+    })
+}
+
 /// Ensures the field has `#[snafu(implicit)]`. Adds it if missing.
 pub(crate) fn ensure_snafu_implicit(field: &mut Field) {
     if !has_snafu_keyword(&field.attrs, "implicit") {
@@ -343,8 +707,8 @@ pub(crate) fn ensure_snafu_implicit(field: &mut Field) {
 /// would NOT match. This is the desired behavior for current snafu
 /// syntax where keywords are always top-level.
 ///
-/// Used by [`has_snafu_keyword`] for best-effort keyword detection without
-/// full Meta parsing.
+/// Used by [`has_snafu_keyword`], [`has_stack_keyword`], and [`has_suzu_keyword`] for best-effort
+/// keyword detection without full Meta parsing.
 fn snafu_tokens_contain_keyword(tokens: &TokenStream, keyword: &str) -> bool {
     let mut at_start = true;
     for tt in tokens.clone() {
@@ -376,6 +740,29 @@ pub(crate) fn combine_errors(errors: Vec<Error>) -> Result<(), Error> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_is_non_location_type() {
+        for ty in [
+            "String",
+            "std::string::String",
+            "&'static str",
+            "u32",
+            "(u32, u32)",
+        ] {
+            let ty: Type = syn::parse_str(ty).unwrap();
+            assert!(is_non_location_type(&ty), "{}", quote::quote!(#ty));
+        }
+        for ty in [
+            "Location",
+            "SourceSpan",
+            "&'static Location<'static>",
+            "MySpan<u32>",
+        ] {
+            let ty: Type = syn::parse_str(ty).unwrap();
+            assert!(!is_non_location_type(&ty), "{}", quote::quote!(#ty));
+        }
+    }
+
     #[test]
     fn test_snafu_tokens_contain_keyword_basic() {
         let tokens: TokenStream = "source".parse().unwrap();
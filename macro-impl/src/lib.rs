@@ -33,6 +33,45 @@ use proc_macro::TokenStream;
 /// When using `#[suzunari_error]`, `#[suzu(location)]` on a field becomes
 /// `#[stack(location)]` + `#[snafu(implicit)]`.
 ///
+/// `location()` reads the field through `AsLocation`, so a field marked with
+/// `#[stack(location)]` may use any type implementing that trait.
+///
+/// Every enum variant must carry its own location field. A derive cannot add
+/// fields, so unit variants are rejected here; `#[suzunari_error]` rewrites
+/// them into `Variant { location: Location }` before this derive runs, which
+/// is how enums mixing unit and named-field variants are supported.
+///
+/// Tuple structs are supported; the location field is resolved the same way
+/// and accessed positionally. Unit structs have no room for a location, so
+/// they are rejected — `#[suzunari_error]` converts them by injecting one.
+///
+/// Fields marked with `#[stack(meta)]` are exposed through
+/// `StackError::metadata()`, keyed by field name. They must implement `Display`.
+///
+/// With the `backtrace` feature, the field marked `#[stack(backtrace)]` (or a
+/// field named `backtrace`) is exposed through `StackError::backtrace()`.
+///
+/// `type_name()` returns the type name, or `Enum::Variant` for enums.
+/// `#[stack(rename = "...")]` on the type or a variant replaces the respective
+/// part, and `#[stack(rename(full = "..."))]` on a variant replaces the whole string.
+///
+/// `#[stack(severity = info | warning | error | critical)]` on the type or a
+/// variant generates `severity()`; a variant-level value takes precedence.
+///
+/// `#[stack(qualified)]` on the type prefixes every `type_name()` with the
+/// defining module's path, e.g. `app::db::QueryError`.
+///
+/// A type or variant marked `#[snafu(transparent)]` gets `is_transparent()`
+/// returning `true`, so `StackReport` skips its frame.
+///
+/// `#[stack(crate = path)]` on the type makes the generated code refer to
+/// suzunari-error through `path` instead of `::suzunari_error`.
+///
+/// `#[stack(skip_location)]` on the type or a variant drops the location
+/// field requirement: `location()` returns the source field's
+/// `StackError::location()` instead. Having a location field as well, or no
+/// source, is an error.
+///
 /// Also generates `From<T> for BoxedStackError` when the `alloc` feature is enabled.
 #[proc_macro_derive(StackError, attributes(stack))]
 pub fn derive_stack_error(input: TokenStream) -> TokenStream {
@@ -50,14 +89,52 @@ pub fn derive_stack_error(input: TokenStream) -> TokenStream {
 /// # `#[suzu(...)]` attributes
 ///
 /// `#[suzu(...)]` is a superset of `#[snafu(...)]`. All snafu keywords are
-/// passed through as-is. Additionally:
+/// passed through as-is. With `context(false)`, the location is captured
+/// where `?` performs the conversion. The location field, injected or not,
+/// is an ordinary field, so `display(...)` can embed it as `{location}` (or
+/// the custom field's name). Additionally:
 ///
 /// - **`from`** (field-level): Wraps the field type in `DisplayError<T>` and
 ///   generates a `source(from(...))` conversion that automatically preserves the
 ///   `Error::source()` chain when the wrapped type implements `Error`.
+///   `from(boxed)` instead retypes a `StackError` source to `BoxedStackError`
+///   via `source(from(T, BoxedStackError::new))` (requires `alloc`).
+///   `from(T, with = f)` keeps the field type and generates
+///   `source(from(T, f))`, for a custom conversion from `T` to the field type.
 /// - **`location`** (field-level): Marks a field as the location field. Converts
 ///   to `#[stack(location)]` + `#[snafu(implicit)]`. Allows custom field names
-///   instead of the default `location`. Requires a `Location` type.
+///   instead of the default `location`. The field type must be `Location` or
+///   implement both `AsLocation` and `snafu::GenerateImplicitData`.
+/// - **`meta`** (field-level): Exposes the field as key-value metadata via
+///   `StackError::metadata()`. Converts to `#[stack(meta)]`. Requires a
+///   `Display` type.
+/// - **`backtrace`** (field-level, `backtrace` feature): Exposes a `Backtrace`
+///   or `Option<Backtrace>` field via `StackError::backtrace()`. Converts to
+///   `#[stack(backtrace)]` + `#[snafu(backtrace)]`.
+/// - **`rename`** (type/variant-level): Overrides the `StackError::type_name()`
+///   string, e.g. `#[suzu(rename = "DiskFull")]`. On a variant it replaces only
+///   the variant portion of `Enum::Variant`; `rename(full = "...")` replaces the
+///   whole string. Converts to `#[stack(rename ...)]`.
+/// - **`severity`** (type/variant-level): Sets `StackError::severity()`, e.g.
+///   `#[suzu(severity = critical)]`. A variant-level severity overrides the
+///   type-level one. Converts to `#[stack(severity = ...)]`.
+/// - **`qualified`** (type-level): Prefixes `StackError::type_name()` with the
+///   defining module's path. Converts to `#[stack(qualified)]`.
+/// - **`crate`** (type-level): Path to suzunari-error for crates that depend on
+///   it under another name or through a re-exporting facade, e.g.
+///   `#[suzu(crate = my_facade::errors)]`. The path must name the crate root.
+///   Converts to `#[stack(crate = ...)]`.
+/// - **`skip_location`** (type/variant-level): Injects no location field and
+///   takes `location()` from the source, which must be a `StackError`. Useful
+///   for `context(false)` wrappers whose frame would only repeat the `?` site.
+///   Converts to `#[stack(skip_location)]`.
+///
+/// # Enum variant shapes
+///
+/// Unit variants get an injected location field. A single-field tuple variant
+/// `V(T)` is rewritten to `V { source: T }`, so its field attributes (e.g.,
+/// `V(#[suzu(from)] T)`) apply to the source. Tuple variants with more than
+/// one field are rejected.
 #[proc_macro_attribute]
 pub fn suzunari_error(attr: TokenStream, item: TokenStream) -> TokenStream {
     let attr2: proc_macro2::TokenStream = attr.into();
@@ -80,6 +157,8 @@ pub fn suzunari_error(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// Primarily designed for `fn main()` where `StackReport`'s `Termination` impl
 /// formats error chains on failure. Can also be applied to other functions to
 /// convert `Result<(), E>` to `StackReport<E>` (e.g., for testing).
+/// Parameters, including `mut` and destructuring patterns, stay on the
+/// rewritten function, so it is called like the original.
 ///
 /// # Usage
 ///
@@ -95,15 +174,41 @@ pub fn suzunari_error(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// # Keeping the original function
+///
+/// `#[suzunari_error::report(keep_inner = run_inner)]` also emits the
+/// untouched body as a private `fn run_inner(..) -> Result<(), E>`, which the
+/// wrapper calls. Tests can then assert on the `Result` directly:
+///
+/// ```rust,ignore
+/// #[suzunari_error::report(keep_inner = run_inner)]
+/// fn run(path: &str) -> Result<(), AppError> {
+///     load(path)?;
+///     Ok(())
+/// }
+///
+/// assert!(run_inner("missing.toml").is_err());
+/// ```
+///
+/// The name must differ from the function's own. Attributes written below
+/// `#[report]` apply to both functions, except doc and test attributes, which
+/// stay on the wrapper, and `#[expect(...)]`, which moves to `run_inner`
+/// with the body it is about.
+///
 /// # Limitations
 ///
 /// - Does not support generics, `where` clauses, `async fn`, `const fn`,
 ///   `unsafe fn`, or `extern fn`.
 /// - Return type must be written literally as `Result<(), E>` (type aliases
 ///   are not resolved).
-/// - Function parameters with complex patterns (e.g., `(a, b): (u32, u32)`)
-///   are forwarded as-is to the generated closure, which may not compile
-///   depending on the pattern form.
+/// - Associated functions in `impl` blocks, including trait impls, are
+///   supported. Since the signature is rewritten, a trait must declare the
+///   method as returning `StackReport<E>`; a trait that requires
+///   `Result<(), E>` cannot be implemented with `#[report]`.
+/// - With `keep_inner`, the wrapper calls `Self::name(self, ..)` for a
+///   method with a `self` receiver, and otherwise the bare `name(..)`. A
+///   macro cannot tell a receiver-less associated function from a free one,
+///   so write `keep_inner = Self::name` for those.
 #[proc_macro_attribute]
 pub fn report(attr: TokenStream, item: TokenStream) -> TokenStream {
     report_impl(attr.into(), item.into())
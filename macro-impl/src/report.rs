@@ -1,19 +1,22 @@
 use crate::helper::get_crate_path;
-use proc_macro2::TokenStream;
-use quote::quote;
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote, quote_spanned};
+use syn::parse::Parser;
+use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
-use syn::{Error, ItemFn, ReturnType, Type};
+use syn::{Attribute, Error, Expr, FnArg, ItemFn, Meta, ReturnType, Type};
 
 pub(crate) fn report_impl(attr: TokenStream, stream: TokenStream) -> Result<TokenStream, Error> {
-    // #[report] takes no arguments
-    if !attr.is_empty() {
-        return Err(Error::new(
-            attr.span(),
-            "#[report] does not accept arguments",
-        ));
-    }
-
+    let keep_inner = parse_report_args(attr)?;
     let input: ItemFn = syn::parse2(stream)?;
+    if let Some(KeepInner { name, .. }) = &keep_inner {
+        if *name == input.sig.ident {
+            return Err(Error::new(
+                name.span(),
+                "`keep_inner` must name a different function than the one #[report] is applied to",
+            ));
+        }
+    }
 
     // Reject function qualifiers that the closure wrap cannot preserve.
     if input.sig.asyncness.is_some() {
@@ -73,6 +76,51 @@ pub(crate) fn report_impl(attr: TokenStream, stream: TokenStream) -> Result<Toke
     let attrs = &input.attrs;
     let original_return_type = return_type;
 
+    if let Some(KeepInner {
+        name: inner,
+        self_path,
+    }) = keep_inner
+    {
+        // The wrapper binds each parameter to a plain name and forwards it,
+        // so patterns (`mut`, destructuring) only appear on the inner fn.
+        let mut call_args = Vec::new();
+        let mut has_receiver = false;
+        let wrapper_inputs = sig_inputs.iter().enumerate().map(|(i, arg)| match arg {
+            FnArg::Receiver(receiver) => {
+                has_receiver = true;
+                call_args.push(quote! { self });
+                let mut receiver = receiver.clone();
+                receiver.mutability = None;
+                quote! { #receiver }
+            }
+            FnArg::Typed(pat_type) => {
+                let name = format_ident!("__report_arg{}", i);
+                let ty = &pat_type.ty;
+                call_args.push(quote! { #name });
+                quote! { #name: #ty }
+            }
+        });
+        let wrapper_inputs: Vec<_> = wrapper_inputs.collect();
+        let (wrapper_attrs, inner_attrs) = split_keep_inner_attrs(attrs);
+        // A receiver-less associated fn can't be told apart from a free fn, so
+        // it needs `keep_inner = Self::name`. The bare call is spanned to the
+        // argument, so a failed lookup points there.
+        let callee = if has_receiver || self_path {
+            quote! { Self::#inner }
+        } else {
+            quote_spanned! {inner.span()=> #inner }
+        };
+        return Ok(quote! {
+            #(#inner_attrs)*
+            fn #inner(#sig_inputs) -> #original_return_type #body
+
+            #(#wrapper_attrs)*
+            #vis fn #sig_ident(#(#wrapper_inputs),*) -> #crate_path::StackReport<#error_type> {
+                #callee(#(#call_args),*).into()
+            }
+        });
+    }
+
     Ok(quote! {
         #(#attrs)*
         #vis fn #sig_ident(#sig_inputs) -> #crate_path::StackReport<#error_type> {
@@ -81,6 +129,85 @@ pub(crate) fn report_impl(attr: TokenStream, stream: TokenStream) -> Result<Toke
     })
 }
 
+/// Splits the attributes of a `keep_inner` function between the wrapper and
+/// the inner fn, which holds the original body.
+///
+/// Doc and test attributes describe the public function and stay on the
+/// wrapper alone. `#[expect(...)]` goes on the inner fn alone, since the
+/// wrapper would leave it unfulfilled. Everything else, such as `cfg`, other
+/// lint levels, and `#[inline]`, applies to both.
+fn split_keep_inner_attrs(attrs: &[Attribute]) -> (Vec<&Attribute>, Vec<&Attribute>) {
+    const WRAPPER_ONLY: &[&str] = &["doc", "test", "should_panic", "ignore"];
+    let mut wrapper = Vec::new();
+    let mut inner = Vec::new();
+    for attr in attrs {
+        let path = attr.path();
+        if WRAPPER_ONLY.iter().any(|name| path.is_ident(name)) {
+            wrapper.push(attr);
+        } else if path.is_ident("expect") {
+            inner.push(attr);
+        } else {
+            wrapper.push(attr);
+            inner.push(attr);
+        }
+    }
+    (wrapper, inner)
+}
+
+/// The parsed `keep_inner = name` or `keep_inner = Self::name` argument.
+struct KeepInner {
+    name: Ident,
+    /// Whether the name was written as `Self::name`.
+    self_path: bool,
+}
+
+/// Parses the `#[report(...)]` arguments; `keep_inner = name` (or
+/// `Self::name`) is the only one.
+fn parse_report_args(attr: TokenStream) -> Result<Option<KeepInner>, Error> {
+    const USAGE: &str = "expected `keep_inner = name` or `keep_inner = Self::name`";
+    let args = Punctuated::<Meta, syn::Token![,]>::parse_terminated.parse2(attr)?;
+    let mut keep_inner: Option<KeepInner> = None;
+    for meta in &args {
+        if !meta.path().is_ident("keep_inner") {
+            return Err(Error::new(
+                meta.span(),
+                "unknown #[report] argument; only `keep_inner = name` is supported",
+            ));
+        }
+        let Meta::NameValue(nv) = meta else {
+            return Err(Error::new(meta.span(), USAGE));
+        };
+        let Expr::Path(path) = &nv.value else {
+            return Err(Error::new(nv.value.span(), USAGE));
+        };
+        let segments = &path.path.segments;
+        let self_path = segments.len() == 2 && segments[0].ident == "Self";
+        let Some(last) = segments.last() else {
+            return Err(Error::new(nv.value.span(), USAGE));
+        };
+        if path.qself.is_some()
+            || path.path.leading_colon.is_some()
+            || !(segments.len() == 1 || self_path)
+            || segments.iter().any(|s| !s.arguments.is_none())
+        {
+            return Err(Error::new(nv.value.span(), USAGE));
+        }
+        if let Some(first) = &keep_inner {
+            let mut err = Error::new(meta.span(), "duplicate `keep_inner`; specify it only once");
+            err.combine(Error::new(
+                first.name.span(),
+                "first occurrence of `keep_inner` is here",
+            ));
+            return Err(err);
+        }
+        keep_inner = Some(KeepInner {
+            name: last.ident.clone(),
+            self_path,
+        });
+    }
+    Ok(keep_inner)
+}
+
 /// Extracts `E` from `Result<(), E>`.
 fn extract_result_error_type(ty: &Type) -> Result<&Type, Error> {
     let Type::Path(type_path) = ty else {
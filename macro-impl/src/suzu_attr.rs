@@ -1,36 +1,55 @@
 //! Processes `#[suzu(...)]` attributes on types, variants, and fields.
 //!
 //! `#[suzu(...)]` is a superset of `#[snafu(...)]`: suzunari-specific keywords
-//! (`from`, `location`) are handled here, and everything else is passed
-//! through as `#[snafu(...)]`.
+//! (`from`, `location`, `meta`, `backtrace`, `rename`, `severity`, `qualified`, `crate`,
+//! `skip_location`) are handled here, and everything else is passed through as
+//! `#[snafu(...)]`.
 
 use crate::helper::{
-    combine_errors, extract_display_error_inner, has_snafu_keyword, looks_like_location_type,
+    combine_errors, extract_display_error_inner, has_snafu_keyword, has_stack_keyword,
+    has_suzu_keyword, is_non_location_type, looks_like_location_type,
 };
 use proc_macro2::{Span, TokenStream};
 use std::collections::HashSet;
+use syn::ext::IdentExt;
 use syn::parse_quote;
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
-use syn::{Attribute, Data, DeriveInput, Error, Field, Fields, GenericParam, Ident, Meta, Token};
+use syn::{
+    Attribute, Data, DeriveInput, Error, Expr, ExprLit, Field, Fields, GenericParam, Ident, Lit,
+    Meta, Token,
+};
 
 /// Processes all `#[suzu(...)]` attributes on `input`, consuming them.
 ///
-/// - `from` and `location` are handled as suzunari extensions.
+/// - `from`, `location`, `meta`, `backtrace`, `rename`, `severity`, `qualified`,
+///   `crate`, and `skip_location` are handled as suzunari extensions.
 /// - All other tokens are forwarded as `#[snafu(...)]`.
 ///
 /// After this call, `#[suzu(location)]` fields have `#[stack(location)]` +
-/// `#[snafu(implicit)]`, and `#[suzu(from)]` fields have their type wrapped in
+/// `#[snafu(implicit)]`, `#[suzu(meta)]` fields have `#[stack(meta)]`, and `#[suzu(from)]` fields have their type wrapped in
 /// `DisplayError<T>` with a `#[snafu(source(from(...)))]` attribute that uses a
 /// local `__wrap` function to resolve source chain delegation at compile time via
-/// autoref specialization.
+/// autoref specialization. `#[suzu(from(boxed))]` fields are retyped to
+/// `BoxedStackError` with `#[snafu(source(from(T, BoxedStackError::new)))]`.
+/// `#[suzu(from(T, with = f))]` fields keep their type and get
+/// `#[snafu(source(from(T, f)))]`.
+/// `#[suzu(backtrace)]` fields have `#[stack(backtrace)]` + `#[snafu(backtrace)]`.
+/// `#[suzu(rename ...)]`, `#[suzu(severity = ...)]`, `#[suzu(qualified)]`,
+/// `#[suzu(crate = ...)]`, and `#[suzu(skip_location)]` on a type or variant become
+/// `#[stack(...)]`, validated by `derive(StackError)`.
 ///
 pub(crate) fn process_suzu_attrs(
     input: &mut DeriveInput,
     crate_path: &TokenStream,
 ) -> Result<(), Error> {
     // Type-level attrs are always passthrough-only, regardless of struct/enum.
-    process_non_field_attrs(&mut input.attrs)?;
+    // Only a struct's display string can refer to fields.
+    let struct_fields = match &input.data {
+        Data::Struct(data_struct) => display_field_names(&input.attrs, &data_struct.fields),
+        _ => None,
+    };
+    process_non_field_attrs(&mut input.attrs, struct_fields.as_ref())?;
 
     let generic_type_params: HashSet<Ident> = input
         .generics
@@ -59,7 +78,9 @@ pub(crate) fn process_suzu_attrs(
             // problem at once, matching the pattern in derive.rs's generate_enum_impl.
             let mut errors = Vec::new();
             for variant in &mut data_enum.variants {
-                if let Err(e) = process_non_field_attrs(&mut variant.attrs) {
+                let variant_fields = display_field_names(&variant.attrs, &variant.fields);
+                if let Err(e) = process_non_field_attrs(&mut variant.attrs, variant_fields.as_ref())
+                {
                     errors.push(e);
                 }
                 match &mut variant.fields {
@@ -102,8 +123,15 @@ fn reject_suzu_on_non_named_fields(fields: &Fields) -> Result<(), Error> {
 }
 
 /// Processes `#[suzu(...)]` on type/variant-level attributes.
-/// Only passthrough to `#[snafu(...)]` is allowed; `from`/`location` are errors.
-fn process_non_field_attrs(attrs: &mut Vec<Attribute>) -> Result<(), Error> {
+/// Only `rename`, `severity`, `qualified`, `crate`, `skip_location`, and passthrough to
+/// `#[snafu(...)]` are allowed; field keywords are errors.
+///
+/// With `fields`, the placeholders of a `display("...")` format string are
+/// checked against them; see [`validate_display_placeholders`].
+fn process_non_field_attrs(
+    attrs: &mut Vec<Attribute>,
+    fields: Option<&HashSet<String>>,
+) -> Result<(), Error> {
     let level = Level::NonField;
     let mut new_attrs = Vec::new();
     let mut errors = Vec::new();
@@ -116,8 +144,17 @@ fn process_non_field_attrs(attrs: &mut Vec<Attribute>) -> Result<(), Error> {
         match process_single_suzu_attr(&attr, level) {
             Ok(result) => {
                 if let Some(snafu_attr) = result.snafu_passthrough {
+                    if let Some(fields) = fields {
+                        if let Err(e) = validate_display_placeholders(&snafu_attr, fields) {
+                            errors.push(e);
+                        }
+                    }
                     new_attrs.push(snafu_attr);
                 }
+                if !result.stack_passthrough.is_empty() {
+                    let stack_tokens = result.stack_passthrough;
+                    new_attrs.push(parse_quote!(#[stack(#(#stack_tokens),*)]));
+                }
             }
             Err(e) => errors.push(e),
         }
@@ -127,6 +164,145 @@ fn process_non_field_attrs(attrs: &mut Vec<Attribute>) -> Result<(), Error> {
     combine_errors(errors)
 }
 
+/// Returns the names a display format string of a struct/variant may refer
+/// to, or `None` for unnamed fields, which are rejected elsewhere.
+///
+/// Includes `location` when `#[suzunari_error]` will inject it, i.e., when
+/// the struct/variant (with attributes `attrs`) is not marked `skip_location`
+/// and no field is marked `#[suzu(location)]`/`#[stack(location)]` or has the
+/// `Location` type.
+fn display_field_names(attrs: &[Attribute], fields: &Fields) -> Option<HashSet<String>> {
+    let skips_location =
+        has_suzu_keyword(attrs, "skip_location") || has_stack_keyword(attrs, "skip_location");
+    let declares_location = fields.iter().any(|field| {
+        looks_like_location_type(&field.ty)
+            || has_suzu_keyword(&field.attrs, "location")
+            || has_stack_keyword(&field.attrs, "location")
+    });
+    let mut names: HashSet<String> = match fields {
+        Fields::Named(fields) => fields
+            .named
+            .iter()
+            .filter_map(|field| field.ident.as_ref())
+            .map(|ident| ident.unraw().to_string())
+            .collect(),
+        Fields::Unit => HashSet::new(),
+        Fields::Unnamed(_) => return None,
+    };
+    if !skips_location && !declares_location {
+        names.insert("location".to_owned());
+    }
+    Some(names)
+}
+
+/// Checks that the named placeholders of `display("...", ...)` in a
+/// `#[snafu(...)]` attribute refer to `fields` or to named arguments of the
+/// same `display(...)`, so a typo points at the format string instead of
+/// snafu's expansion.
+///
+/// Only names that can be fields are checked: SCREAMING_CASE names are left
+/// to `format_args!`, which captures in-scope constants such as
+/// `display("over {LIMIT}")`. Non-literal format strings are left to snafu.
+fn validate_display_placeholders(attr: &Attribute, fields: &HashSet<String>) -> Result<(), Error> {
+    let Ok(nested) = attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated) else {
+        return Ok(());
+    };
+    let mut errors = Vec::new();
+    for meta in &nested {
+        let Meta::List(list) = meta else { continue };
+        if !list.path.is_ident("display") {
+            continue;
+        }
+        let Ok(args) = list.parse_args_with(Punctuated::<Expr, Token![,]>::parse_terminated) else {
+            continue;
+        };
+        let mut args = args.into_iter();
+        let Some(Expr::Lit(ExprLit {
+            lit: Lit::Str(format),
+            ..
+        })) = args.next()
+        else {
+            continue;
+        };
+        let named_args: HashSet<String> = args
+            .filter_map(|arg| match arg {
+                Expr::Assign(assign) => match *assign.left {
+                    Expr::Path(path) => path.path.get_ident().map(|ident| ident.to_string()),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .collect();
+
+        let mut reported = HashSet::new();
+        for name in format_placeholder_names(&format.value()) {
+            if fields.contains(&name)
+                || named_args.contains(&name)
+                || is_screaming_case(&name)
+                || !reported.insert(name.clone())
+            {
+                continue;
+            }
+            errors.push(Error::new(
+                format.span(),
+                format!("display format string refers to unknown field `{name}`"),
+            ));
+        }
+    }
+    combine_errors(errors)
+}
+
+/// Returns whether `name` is a constant-style name (`LIMIT`, `MAX_2`), which a
+/// format string may capture from the surrounding scope.
+fn is_screaming_case(name: &str) -> bool {
+    name.chars().any(|c| c.is_uppercase()) && !name.chars().any(|c| c.is_lowercase())
+}
+
+/// Extracts the named arguments of a format string: `{name}`, `{name:?}`,
+/// and width/precision references such as `{:width$}`. Positional arguments
+/// and escaped braces are skipped; malformed strings yield what was parsed
+/// so far and are left for `format_args!` to report.
+fn format_placeholder_names(format: &str) -> Vec<String> {
+    fn is_identifier(s: &str) -> bool {
+        s.starts_with(|c: char| c.is_alphabetic() || c == '_')
+            && s.chars().all(|c| c.is_alphanumeric() || c == '_')
+            && s != "_"
+    }
+
+    let mut names = Vec::new();
+    let mut rest = format;
+    while let Some(start) = rest.find(['{', '}']) {
+        let (brace, after) = rest[start..].split_at(1);
+        if after.starts_with(brace) {
+            // `{{` or `}}`
+            rest = &after[1..];
+            continue;
+        }
+        if brace == "}" {
+            rest = after;
+            continue;
+        }
+        let Some(end) = after.find('}') else {
+            break;
+        };
+        let (argument, spec) = after[..end].split_once(':').unwrap_or((&after[..end], ""));
+        let argument = argument.trim();
+        if is_identifier(argument) {
+            names.push(argument.to_owned());
+        }
+        for reference in spec.split('$').rev().skip(1) {
+            let name = reference
+                .rsplit(|c: char| !(c.is_alphanumeric() || c == '_'))
+                .next();
+            if let Some(name) = name.filter(|name| is_identifier(name)) {
+                names.push(name.to_owned());
+            }
+        }
+        rest = &after[end + 1..];
+    }
+    names
+}
+
 /// Processes `#[suzu(...)]` attributes on fields within a single struct/variant.
 fn process_fields(
     fields: &mut Punctuated<Field, Token![,]>,
@@ -138,6 +314,7 @@ fn process_fields(
     // Both from and location allow at most one per struct/variant.
     let mut first_from_span: Option<Span> = None;
     let mut first_location_span: Option<Span> = None;
+    let mut first_backtrace_span: Option<Span> = None;
 
     for field in fields.iter_mut() {
         // Take ownership of attrs to avoid borrow conflicts when mutating field.ty
@@ -145,8 +322,13 @@ fn process_fields(
         let mut new_attrs = Vec::new();
         // Per-field span: Some(span) means this field has the keyword.
         // first_from_span/first_location_span track cross-field duplicates.
-        let mut current_from_span: Option<Span> = None;
+        let mut current_from_span: Option<(Span, FromKind)> = None;
         let mut current_location_span: Option<Span> = None;
+        let mut current_meta_span: Option<Span> = None;
+        let mut current_backtrace_span: Option<Span> = None;
+        // `source(...)` passed through any #[suzu(...)] on this field, for the
+        // cross-attr `from` conflict check below.
+        let mut current_source_span: Option<Span> = None;
 
         for attr in old_attrs {
             if !attr.path().is_ident("suzu") {
@@ -158,8 +340,42 @@ fn process_fields(
                     if let Some(snafu_attr) = result.snafu_passthrough {
                         new_attrs.push(snafu_attr);
                     }
+                    if current_source_span.is_none() {
+                        current_source_span = result.source;
+                    }
+                    if let Some(meta_span) = result.meta {
+                        if let Some(first_span) = current_meta_span {
+                            let mut err =
+                                Error::new(meta_span, "duplicate #[suzu(meta)] on the same field");
+                            err.combine(Error::new(
+                                first_span,
+                                "first occurrence of #[suzu(meta)] is here",
+                            ));
+                            errors.push(err);
+                        } else {
+                            current_meta_span = Some(meta_span);
+                        }
+                    }
+                    if let Some(backtrace_span) = result.backtrace {
+                        if let Some(first_span) = first_backtrace_span {
+                            let msg = if current_backtrace_span.is_some() {
+                                "duplicate #[suzu(backtrace)] on the same field"
+                            } else {
+                                "multiple #[suzu(backtrace)] fields; only one is allowed per struct/variant"
+                            };
+                            let mut err = Error::new(backtrace_span, msg);
+                            err.combine(Error::new(
+                                first_span,
+                                "first occurrence of #[suzu(backtrace)] is here",
+                            ));
+                            errors.push(err);
+                        } else {
+                            first_backtrace_span = Some(backtrace_span);
+                            current_backtrace_span = Some(backtrace_span);
+                        }
+                    }
                     match result.effect {
-                        SuzuEffect::From(keyword_span) => {
+                        SuzuEffect::From(keyword_span, kind) => {
                             if let Some(first_span) = first_from_span {
                                 // Distinguish same-field duplicate from cross-field duplicate:
                                 // current_from_span is set when this field already has `from`.
@@ -180,7 +396,7 @@ fn process_fields(
                                 current_from_span = None;
                             } else {
                                 first_from_span = Some(keyword_span);
-                                current_from_span = Some(keyword_span);
+                                current_from_span = Some((keyword_span, kind));
                             }
                         }
                         SuzuEffect::Location(keyword_span) => {
@@ -214,6 +430,21 @@ fn process_fields(
             }
         }
 
+        // Cross-attr: #[suzu(from)] #[suzu(source(...))]. The within-attr form
+        // is caught in process_single_suzu_attr. Clear current_from_span so
+        // apply_from does not report the generated #[snafu(source(...))] again.
+        if let (Some((from_span, _)), Some(source_span)) = (&current_from_span, current_source_span)
+        {
+            let from_span = *from_span;
+            let mut err = Error::new(
+                from_span,
+                "`from` conflicts with `source(...)`: `from` generates `source(from(...))` automatically",
+            );
+            err.combine(Error::new(source_span, "`source(...)` defined here"));
+            errors.push(err);
+            current_from_span = None;
+        }
+
         // Apply from/location after the attrs loop so the field is freely borrowable.
         //
         // from+location conflict is checked in three places:
@@ -223,7 +454,7 @@ fn process_fields(
         // (1) and (2) provide better spans (pointing to the conflicting keyword),
         // while (3) catches the cross-attribute case that within-attr checks cannot see.
         match (current_from_span, current_location_span) {
-            (Some(from_span), Some(loc_span)) => {
+            (Some((from_span, _)), Some(loc_span)) => {
                 let mut err = Error::new(
                     from_span,
                     "`from` and `location` cannot be used on the same field",
@@ -231,7 +462,7 @@ fn process_fields(
                 err.combine(Error::new(loc_span, "`location` defined here"));
                 errors.push(err);
             }
-            (Some(from_span), None) => match apply_from(
+            (Some((from_span, FromKind::DisplayError)), None) => match apply_from(
                 field,
                 &new_attrs,
                 crate_path,
@@ -241,11 +472,27 @@ fn process_fields(
                 Ok(snafu_source_attr) => new_attrs.push(snafu_source_attr),
                 Err(e) => errors.push(e),
             },
+            (Some((from_span, FromKind::Boxed)), None) => {
+                match apply_from_boxed(field, &new_attrs, crate_path, from_span) {
+                    Ok(snafu_source_attr) => new_attrs.push(snafu_source_attr),
+                    Err(e) => errors.push(e),
+                }
+            }
+            (Some((from_span, FromKind::With(args))), None) => {
+                match apply_from_with(&new_attrs, from_span, &args) {
+                    Ok(snafu_source_attr) => new_attrs.push(snafu_source_attr),
+                    Err(e) => errors.push(e),
+                }
+            }
+            // Any type implementing `AsLocation` + `GenerateImplicitData` is
+            // accepted; the generated code enforces both bounds. Types that
+            // can never implement them are rejected here, on the field type.
             (None, Some(_)) => {
-                if !looks_like_location_type(&field.ty) {
+                if is_non_location_type(&field.ty) {
                     errors.push(Error::new(
                         field.ty.span(),
-                        "#[suzu(location)] requires the field type to be `suzunari_error::Location`",
+                        "#[suzu(location)] requires the field type to be \
+                         `suzunari_error::Location` or implement `suzunari_error::AsLocation`",
                     ));
                 } else {
                     apply_location(&mut new_attrs);
@@ -254,6 +501,13 @@ fn process_fields(
             (None, None) => {}
         }
 
+        if current_meta_span.is_some() {
+            apply_meta(&mut new_attrs);
+        }
+        if current_backtrace_span.is_some() {
+            apply_backtrace(&mut new_attrs);
+        }
+
         field.attrs = new_attrs;
     }
 
@@ -262,9 +516,10 @@ fn process_fields(
 
 #[derive(Clone, Copy)]
 enum Level {
-    /// Type-level or variant-level — only passthrough allowed.
+    /// Type-level or variant-level — only `rename`, `severity`, `qualified`, `crate`,
+    /// `skip_location`, and passthrough allowed.
     NonField,
-    /// Field-level — `from` and `location` are valid.
+    /// Field-level — `from`, `location`, `meta`, and `backtrace` are valid.
     Field,
 }
 
@@ -276,17 +531,66 @@ enum Level {
 enum SuzuEffect {
     /// No suzunari keyword — all tokens passed through to snafu.
     PassthroughOnly,
-    /// `from` keyword found — wraps field type in `DisplayError<T>`.
-    From(Span),
+    /// `from` keyword found — wraps field type in `DisplayError<T>`, or in
+    /// `BoxedStackError` for `from(boxed)`.
+    From(Span, FromKind),
     /// `location` keyword found — marks field as the location field.
     Location(Span),
 }
 
+/// Error message for a malformed `from` argument list.
+const FROM_USAGE: &str = "`from` accepts `boxed` or `SourceType, with = conversion` as arguments; \
+     use `#[suzu(from)]`, `#[suzu(from(boxed))]`, or `#[suzu(from(T, with = f))]`";
+
+/// How a `#[suzu(from)]` field converts its source.
+enum FromKind {
+    /// `#[suzu(from)]` — wraps a non-`StackError` source in `DisplayError<T>`.
+    DisplayError,
+    /// `#[suzu(from(boxed))]` — boxes a `StackError` source into `BoxedStackError`.
+    Boxed,
+    /// `#[suzu(from(T, with = f))]` — converts a `T` source with a user function.
+    With(Box<FromWithArgs>),
+}
+
+/// The arguments of `#[suzu(from(T, with = f))]`.
+struct FromWithArgs {
+    source_type: syn::Type,
+    with: Expr,
+}
+
+impl syn::parse::Parse for FromWithArgs {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let source_type = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let keyword: Ident = input.parse()?;
+        if keyword != "with" {
+            return Err(Error::new(keyword.span(), "expected `with = ...`"));
+        }
+        input.parse::<Token![=]>()?;
+        let with = input.parse()?;
+        // Allow a trailing comma, like other attribute lists.
+        if input.peek(Token![,]) {
+            input.parse::<Token![,]>()?;
+        }
+        Ok(Self { source_type, with })
+    }
+}
+
 struct SingleAttrResult {
     /// The passthrough `#[snafu(...)]` attribute, if any non-suzunari tokens exist.
     snafu_passthrough: Option<Attribute>,
     /// Which suzunari extension (if any) was requested.
     effect: SuzuEffect,
+    /// Span of the `meta` keyword, if present. Independent of `effect`
+    /// because `meta` can be combined with any other keyword.
+    meta: Option<Span>,
+    /// Span of the `backtrace` keyword, if present.
+    backtrace: Option<Span>,
+    /// Span of a `source` passthrough entry, if present.
+    source: Option<Span>,
+    /// `rename`/`severity`/`qualified`/`crate`/`skip_location` entries, forwarded as-is
+    /// to `#[stack(...)]`.
+    stack_passthrough: Vec<Meta>,
 }
 
 /// Parses a single `#[suzu(...)]` attribute.
@@ -311,18 +615,29 @@ fn process_single_suzu_attr(attr: &Attribute, level: Level) -> Result<SingleAttr
     }
 
     let mut effect = SuzuEffect::PassthroughOnly;
+    let mut meta_span: Option<Span> = None;
+    let mut backtrace_span: Option<Span> = None;
+    let mut stack_passthrough: Vec<Meta> = Vec::new();
     let mut passthrough_tokens: Vec<Meta> = Vec::new();
-    let mut has_source_in_passthrough = false;
+    let mut source_span: Option<Span> = None;
 
     for meta in &nested {
         if meta.path().is_ident("from") {
-            // `from` must be a bare keyword — reject list/name-value forms
-            if !matches!(meta, Meta::Path(_)) {
-                return Err(Error::new(
-                    meta.span(),
-                    "`from` does not accept arguments; use `#[suzu(from)]` as a bare keyword",
-                ));
-            }
+            // `from` is a bare keyword, `from(boxed)`, or `from(T, with = f)` —
+            // reject anything else
+            let kind = match meta {
+                Meta::Path(_) => FromKind::DisplayError,
+                Meta::List(list) if list.parse_args::<Ident>().is_ok_and(|i| i == "boxed") => {
+                    FromKind::Boxed
+                }
+                Meta::List(list) => list
+                    .parse_args::<FromWithArgs>()
+                    .map(|args| FromKind::With(Box::new(args)))
+                    .map_err(|_| Error::new(meta.span(), FROM_USAGE))?,
+                Meta::NameValue(_) => {
+                    return Err(Error::new(meta.span(), FROM_USAGE));
+                }
+            };
             if matches!(level, Level::NonField) {
                 return Err(Error::new(meta.span(), "`from` can only be used on fields"));
             }
@@ -333,7 +648,7 @@ fn process_single_suzu_attr(attr: &Attribute, level: Level) -> Result<SingleAttr
                     "`from` and `location` cannot be used on the same field",
                 ));
             }
-            effect = SuzuEffect::From(meta.span());
+            effect = SuzuEffect::From(meta.span(), kind);
         } else if meta.path().is_ident("location") {
             // `location` must be a bare keyword — reject list/name-value forms
             if !matches!(meta, Meta::Path(_)) {
@@ -348,7 +663,7 @@ fn process_single_suzu_attr(attr: &Attribute, level: Level) -> Result<SingleAttr
                     "`location` can only be used on fields",
                 ));
             }
-            if matches!(effect, SuzuEffect::From(_)) {
+            if matches!(effect, SuzuEffect::From(..)) {
                 // Within-attr conflict: #[suzu(from, location)] — point to the `location` keyword.
                 return Err(Error::new(
                     meta.span(),
@@ -356,16 +671,75 @@ fn process_single_suzu_attr(attr: &Attribute, level: Level) -> Result<SingleAttr
                 ));
             }
             effect = SuzuEffect::Location(meta.span());
+        } else if meta.path().is_ident("meta") {
+            // `meta` must be a bare keyword — reject list/name-value forms
+            if !matches!(meta, Meta::Path(_)) {
+                return Err(Error::new(
+                    meta.span(),
+                    "`meta` does not accept arguments; use `#[suzu(meta)]` as a bare keyword",
+                ));
+            }
+            if matches!(level, Level::NonField) {
+                return Err(Error::new(meta.span(), "`meta` can only be used on fields"));
+            }
+            if let Some(first_span) = meta_span {
+                let mut err = Error::new(meta.span(), "duplicate `meta` in #[suzu(...)]");
+                err.combine(Error::new(first_span, "first occurrence of `meta` is here"));
+                return Err(err);
+            }
+            meta_span = Some(meta.span());
+        } else if meta.path().is_ident("backtrace") {
+            // `backtrace` must be a bare keyword — reject list/name-value forms
+            if !matches!(meta, Meta::Path(_)) {
+                return Err(Error::new(
+                    meta.span(),
+                    "`backtrace` does not accept arguments; use `#[suzu(backtrace)]` as a bare keyword",
+                ));
+            }
+            if matches!(level, Level::NonField) {
+                return Err(Error::new(
+                    meta.span(),
+                    "`backtrace` can only be used on fields",
+                ));
+            }
+            if !cfg!(feature = "backtrace") {
+                return Err(Error::new(
+                    meta.span(),
+                    "`backtrace` requires the `backtrace` feature of suzunari-error",
+                ));
+            }
+            if let Some(first_span) = backtrace_span {
+                let mut err = Error::new(meta.span(), "duplicate `backtrace` in #[suzu(...)]");
+                err.combine(Error::new(
+                    first_span,
+                    "first occurrence of `backtrace` is here",
+                ));
+                return Err(err);
+            }
+            backtrace_span = Some(meta.span());
+        } else if let Some(keyword) = ["rename", "severity", "qualified", "crate", "skip_location"]
+            .into_iter()
+            .find(|k| meta.path().is_ident(k))
+        {
+            if matches!(level, Level::Field) {
+                return Err(Error::new(
+                    meta.span(),
+                    format!("`{keyword}` can only be used on types and variants"),
+                ));
+            }
+            // Form and duplicates are validated by derive(StackError), which
+            // also owns `#[stack(...)]` when used without #[suzunari_error].
+            stack_passthrough.push(meta.clone());
         } else {
-            if meta.path().is_ident("source") {
-                has_source_in_passthrough = true;
+            if meta.path().is_ident("source") && source_span.is_none() {
+                source_span = Some(meta.span());
             }
             passthrough_tokens.push(meta.clone());
         }
     }
 
     // Conflict: from + source(...) in the same #[suzu(...)]
-    if matches!(effect, SuzuEffect::From(_)) && has_source_in_passthrough {
+    if matches!(effect, SuzuEffect::From(..)) && source_span.is_some() {
         return Err(Error::new(
             attr.span(),
             "`from` conflicts with `source(...)`: `from` generates `source(from(...))` automatically",
@@ -381,6 +755,10 @@ fn process_single_suzu_attr(attr: &Attribute, level: Level) -> Result<SingleAttr
     Ok(SingleAttrResult {
         snafu_passthrough,
         effect,
+        meta: meta_span,
+        backtrace: backtrace_span,
+        source: source_span,
+        stack_passthrough,
     })
 }
 
@@ -460,6 +838,77 @@ fn apply_from(
     ))
 }
 
+/// Applies `from(boxed)` to a field: retypes it to `BoxedStackError` and
+/// generates `#[snafu(source(from(T, BoxedStackError::new)))]`.
+///
+/// Same preconditions as [`apply_from`]. Unlike `apply_from`, generic source
+/// types are allowed: `BoxedStackError::new` needs only trait bounds, not
+/// autoref specialization.
+fn apply_from_boxed(
+    field: &mut Field,
+    existing_attrs: &[Attribute],
+    crate_path: &TokenStream,
+    from_span: Span,
+) -> Result<Attribute, Error> {
+    if !cfg!(feature = "alloc") {
+        return Err(Error::new(
+            from_span,
+            "`from(boxed)` requires the `alloc` feature of suzunari-error",
+        ));
+    }
+    if has_snafu_keyword(existing_attrs, "source") {
+        return Err(Error::new(
+            from_span,
+            "`from` conflicts with existing `#[snafu(source(...))]`",
+        ));
+    }
+    if is_boxed_stack_error_type(&field.ty) {
+        return Err(Error::new(
+            field.ty.span(),
+            "`from(boxed)` expects the source error type, not `BoxedStackError`; \
+             the field is converted to `BoxedStackError` automatically",
+        ));
+    }
+
+    let original_type = field.ty.clone();
+    field.ty = parse_quote!(#crate_path::BoxedStackError);
+    Ok(parse_quote!(
+        #[snafu(source(from(#original_type, #crate_path::BoxedStackError::new)))]
+    ))
+}
+
+/// Applies `from(T, with = f)` to a field: keeps its type and generates
+/// `#[snafu(source(from(T, f)))]`, so `f` must convert `T` into the field type.
+///
+/// Same preconditions as [`apply_from`]. No `DisplayError` wrapping happens,
+/// so the field type decides whether the source is a `StackError`.
+fn apply_from_with(
+    existing_attrs: &[Attribute],
+    from_span: Span,
+    args: &FromWithArgs,
+) -> Result<Attribute, Error> {
+    if has_snafu_keyword(existing_attrs, "source") {
+        return Err(Error::new(
+            from_span,
+            "`from` conflicts with existing `#[snafu(source(...))]`",
+        ));
+    }
+    let FromWithArgs { source_type, with } = args;
+    Ok(parse_quote!(#[snafu(source(from(#source_type, #with)))]))
+}
+
+/// Checks whether the last path segment of `ty` is `BoxedStackError`.
+fn is_boxed_stack_error_type(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Path(type_path) => type_path
+            .path
+            .segments
+            .last()
+            .is_some_and(|seg| seg.ident == "BoxedStackError"),
+        _ => false,
+    }
+}
+
 /// Checks whether `ty` references any of the given generic type parameters.
 fn type_uses_generic_params(ty: &syn::Type, params: &HashSet<Ident>) -> bool {
     use syn::{GenericArgument, PathArguments, ReturnType, Type};
@@ -567,15 +1016,44 @@ fn apply_location(attrs: &mut Vec<Attribute>) {
     }
     // Guard against duplicate #[stack(location)] — can happen if the user
     // writes both #[stack(location)] and #[suzu(location)] on the same field.
-    // Currently #[stack(...)] only accepts `location`, so checking the path
-    // alone is sufficient. If #[stack] gains other arguments in the future,
-    // this must be narrowed to check for the `location` argument specifically.
-    let already_has_stack_location = attrs.iter().any(|a| a.path().is_ident("stack"));
-    if !already_has_stack_location {
+    if !has_stack_keyword(attrs, "location") {
         attrs.push(parse_quote!(#[stack(location)]));
     }
 }
 
+/// Applies `meta` to a field: adds `#[stack(meta)]`.
+///
+/// `#[stack(meta)]` is consumed by `derive(StackError)` to include the field
+/// in `StackError::metadata()`. The field stays a regular snafu context field.
+///
+/// # Preconditions
+///
+/// - `attrs` must contain all attributes that will be set on this field
+///   (i.e., the field's own `attrs` vec is not yet populated).
+fn apply_meta(attrs: &mut Vec<Attribute>) {
+    if !has_stack_keyword(attrs, "meta") {
+        attrs.push(parse_quote!(#[stack(meta)]));
+    }
+}
+
+/// Applies `backtrace` to a field: adds `#[stack(backtrace)]` and `#[snafu(backtrace)]`.
+///
+/// snafu captures the backtrace implicitly; `derive(StackError)` exposes it
+/// through `StackError::backtrace()`.
+///
+/// # Preconditions
+///
+/// - `attrs` must contain all attributes that will be set on this field
+///   (i.e., the field's own `attrs` vec is not yet populated).
+fn apply_backtrace(attrs: &mut Vec<Attribute>) {
+    if !has_snafu_keyword(attrs, "backtrace") {
+        attrs.push(parse_quote!(#[snafu(backtrace)]));
+    }
+    if !has_stack_keyword(attrs, "backtrace") {
+        attrs.push(parse_quote!(#[stack(backtrace)]));
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -620,4 +1098,22 @@ mod tests {
         let stack_count = attrs.iter().filter(|a| a.path().is_ident("stack")).count();
         assert_eq!(stack_count, 1, "should not duplicate #[stack(location)]");
     }
+
+    #[test]
+    fn test_is_screaming_case() {
+        assert!(is_screaming_case("LIMIT"));
+        assert!(is_screaming_case("MAX_2"));
+        assert!(!is_screaming_case("limit"));
+        assert!(!is_screaming_case("Limit"));
+        assert!(!is_screaming_case("_1"));
+    }
+
+    #[test]
+    fn test_format_placeholder_names() {
+        assert_eq!(
+            format_placeholder_names("{a} {b:?} {0} {} {{c}} }} {d:>w$.p$} {e"),
+            ["a", "b", "d", "p", "w"]
+        );
+        assert!(format_placeholder_names("no placeholders").is_empty());
+    }
 }
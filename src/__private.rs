@@ -17,6 +17,29 @@ use crate::display_error::DisplayError;
 use core::error::Error;
 use core::fmt::{Debug, Display};
 
+/// Lets `stack_error!` format messages without requiring `extern crate alloc`
+/// in `no_std` downstream crates.
+#[cfg(feature = "alloc")]
+pub use alloc::format;
+
+// ---------------------------------------------------------------------------
+// ErrorImplemented — explains a missing `Error` impl under derive(StackError)
+// ---------------------------------------------------------------------------
+
+/// Implemented for every `Error`; exists only for its diagnostic, which
+/// names the usual cause of a missing `Error` impl.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not implement `Error`, which `StackError` requires",
+    label = "`Error` is not implemented",
+    note = "add `#[derive(Snafu)]`, or use `#[suzunari_error]`, which derives both"
+)]
+pub trait ErrorImplemented {}
+
+impl<T: Error + ?Sized> ErrorImplemented for T {}
+
+/// Called from code generated by `derive(StackError)`.
+pub fn assert_error_implemented<T: ErrorImplemented + ?Sized>() {}
+
 // ---------------------------------------------------------------------------
 // StackSourceResolver — resolves StackError::stack_source()
 // ---------------------------------------------------------------------------
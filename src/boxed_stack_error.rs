@@ -1,6 +1,8 @@
+use alloc::borrow::Cow;
 use alloc::boxed::Box;
+use alloc::sync::Arc;
 
-use crate::{Location, StackError};
+use crate::{Location, Severity, StackError};
 use core::error::Error;
 use core::fmt::{Debug, Display, Formatter, Result};
 
@@ -11,11 +13,17 @@ use core::fmt::{Debug, Display, Formatter, Result};
 /// of `Box<dyn StackError + Send + Sync>` for shorter type signatures
 /// and automatic `From` generation by the derive macro.
 ///
-/// Note: downcasting to the concrete type is not supported through this
-/// wrapper. Use `into_inner()` if you need the raw trait object.
+/// [`StackError::find`] sees through this wrapper to the concrete type.
+/// Use `into_inner()` if you need the raw trait object.
+///
+/// `std::io::Error` (with `std`), `ParseIntError`, and `Utf8Error` convert
+/// into a `BoxedStackError` directly, so `?` on them at a boundary yields a
+/// frame located at the `?`, named `io::Error`, `ParseIntError`, or
+/// `Utf8Error`, without a dedicated wrapper type.
 ///
 /// `Clone` is not implemented because the inner trait object
-/// (`Box<dyn StackError + Send + Sync>`) cannot be cloned.
+/// (`Box<dyn StackError + Send + Sync>`) cannot be cloned. Convert into a
+/// [`SharedStackError`] to hand the same error to several consumers.
 ///
 /// # Example
 ///
@@ -49,64 +57,508 @@ use core::fmt::{Debug, Display, Formatter, Result};
 /// assert!(err.stack_source().is_some());
 /// ```
 pub struct BoxedStackError {
-    inner: Box<dyn StackError + Send + Sync>,
+    inner: BoxedInner,
+}
+
+/// The two ways a `BoxedStackError` holds its error.
+enum BoxedInner {
+    /// Boxed from a concrete type, which stays reachable for downcasting.
+    Erased(Box<dyn ErasedStackError>),
+    /// An existing trait object, kept as is instead of boxed a second time.
+    /// Its concrete type is unknown, so downcasting stops at the `Box`.
+    Dyn(Box<dyn StackError + Send + Sync>),
+}
+
+/// Object-safe hook that keeps the boxed value's `'static` type reachable
+/// as `dyn Error`, so the concrete type can be recovered by downcasting.
+///
+/// Trait upcasting (`&dyn StackError` → `&dyn Error`) would make this
+/// unnecessary, but requires Rust 1.86, above the crate's MSRV.
+trait ErasedStackError: StackError + Send + Sync {
+    fn as_stack_error(&self) -> &(dyn StackError + Send + Sync);
+    fn as_error(&self) -> &(dyn Error + Send + Sync + 'static);
+    fn into_stack_error(self: Box<Self>) -> Box<dyn StackError + Send + Sync>;
+}
+
+impl<T: StackError + Send + Sync + 'static> ErasedStackError for T {
+    fn as_stack_error(&self) -> &(dyn StackError + Send + Sync) {
+        self
+    }
+    fn as_error(&self) -> &(dyn Error + Send + Sync + 'static) {
+        self
+    }
+    fn into_stack_error(self: Box<Self>) -> Box<dyn StackError + Send + Sync> {
+        self
+    }
 }
 
 impl BoxedStackError {
     /// Wraps a concrete `StackError` in a type-erased box.
+    ///
+    /// `T` may itself be a `Box<dyn StackError + Send + Sync>`, but that
+    /// boxes the box; use [`new_boxed`](Self::new_boxed) for trait objects.
     #[must_use]
     pub fn new<T: StackError + Send + Sync + 'static>(inner: T) -> Self {
         Self {
-            inner: Box::new(inner),
+            inner: BoxedInner::Erased(Box::new(inner)),
         }
     }
 
-    /// Returns a reference to the inner trait object.
+    /// Wraps an already boxed trait object without allocating again.
+    ///
+    /// Same as `BoxedStackError::from(inner)`. [`into_inner`](Self::into_inner)
+    /// returns the same box. Since the concrete type behind the trait object
+    /// is unknown, [`StackError::find`] cannot see past it.
+    ///
+    /// ```
+    /// use suzunari_error::*;
+    ///
+    /// let inner: Box<dyn StackError + Send + Sync> =
+    ///     Box::new(BoxedStackError::msg("cache miss"));
+    /// let err = BoxedStackError::new_boxed(inner);
+    /// assert_eq!(err.type_name(), "Message");
+    /// assert_eq!(err.to_string(), "cache miss");
+    /// ```
+    #[must_use]
+    pub fn new_boxed(inner: Box<dyn StackError + Send + Sync>) -> Self {
+        Self {
+            inner: BoxedInner::Dyn(inner),
+        }
+    }
+
+    /// Creates an ad-hoc error from a message, located at the caller.
+    ///
+    /// Useful at boundaries where defining a dedicated error type is not
+    /// worth it, similar to `anyhow::anyhow!`. Accepts `&'static str` and
+    /// `String`. The error has no source and reports `"Message"` as its
+    /// type name.
+    ///
+    /// ```
+    /// use suzunari_error::*;
+    ///
+    /// let err = BoxedStackError::msg("config missing");
+    /// assert_eq!(err.to_string(), "config missing");
+    /// assert_eq!(err.type_name(), "Message");
+    /// ```
+    #[must_use]
+    #[track_caller]
+    pub fn msg(message: impl Into<Cow<'static, str>>) -> Self {
+        Self::new(MessageError {
+            message: message.into(),
+            location: core::panic::Location::caller(),
+        })
+    }
+
+    /// Returns a reference to the inner trait object, the borrowing
+    /// counterpart of [`into_inner`](Self::into_inner).
+    ///
+    /// `&BoxedStackError` also coerces to `&dyn StackError` directly, and
+    /// keeps [`StackError::find`] able to reach the concrete type.
     #[must_use]
     pub fn inner(&self) -> &(dyn StackError + Send + Sync) {
-        &*self.inner
+        match &self.inner {
+            BoxedInner::Erased(inner) => inner.as_stack_error(),
+            BoxedInner::Dyn(inner) => &**inner,
+        }
     }
 
     /// Unwraps into the inner trait object.
     #[must_use]
     pub fn into_inner(self) -> Box<dyn StackError + Send + Sync> {
-        self.inner
+        match self.inner {
+            BoxedInner::Erased(inner) => inner.into_stack_error(),
+            BoxedInner::Dyn(inner) => inner,
+        }
+    }
+
+    /// Returns a cheap identity for deduplicating repeated errors: the
+    /// type name and the location where the error was created.
+    ///
+    /// Messages are not part of the fingerprint, so errors from the same
+    /// site with different field values share it. The tuple is `Hash + Eq`
+    /// and can be used as a map key.
+    #[must_use]
+    pub fn fingerprint(&self) -> (&'static str, Location) {
+        (self.type_name(), self.location())
+    }
+
+    /// Returns whether `self` and `other` share a [`fingerprint`](Self::fingerprint),
+    /// i.e., have the same type name and were created at the same location.
+    ///
+    /// ```
+    /// use suzunari_error::*;
+    ///
+    /// fn fail(message: &'static str) -> BoxedStackError {
+    ///     BoxedStackError::msg(message)
+    /// }
+    ///
+    /// let a = fail("first");
+    /// let b = fail("second");
+    /// assert!(a.same_site(&b));
+    /// assert!(!a.same_site(&BoxedStackError::msg("elsewhere")));
+    /// ```
+    #[must_use]
+    pub fn same_site(&self, other: &Self) -> bool {
+        self.fingerprint() == other.fingerprint()
+    }
+
+    /// Returns the inner error as a `'static` `dyn Error`, which supports
+    /// downcasting to its concrete type.
+    pub(crate) fn inner_error(&self) -> &(dyn Error + 'static) {
+        match &self.inner {
+            BoxedInner::Erased(inner) => inner.as_error(),
+            BoxedInner::Dyn(inner) => inner,
+        }
     }
 }
 
 impl Display for BoxedStackError {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        write!(f, "{}", self.inner)
+        write!(f, "{}", self.inner())
     }
 }
 
 impl Debug for BoxedStackError {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        write!(f, "{:?}", self.inner)
+        write!(f, "{:?}", self.inner())
     }
 }
 
 impl Error for BoxedStackError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
-        self.inner.source()
+        self.inner().source()
     }
 }
 
 impl StackError for BoxedStackError {
+    fn location(&self) -> Location {
+        self.inner().location()
+    }
+    fn type_name(&self) -> &'static str {
+        self.inner().type_name()
+    }
+    fn module_path(&self) -> Option<&'static str> {
+        self.inner().module_path()
+    }
+    fn severity(&self) -> Severity {
+        self.inner().severity()
+    }
+    fn is_transparent(&self) -> bool {
+        self.inner().is_transparent()
+    }
+    fn stack_source(&self) -> Option<&dyn StackError> {
+        self.inner().stack_source()
+    }
+    fn metadata(&self, visitor: &mut dyn FnMut(&'static str, &dyn Display)) {
+        self.inner().metadata(visitor)
+    }
+    #[cfg(feature = "backtrace")]
+    fn backtrace(&self) -> Option<&std::backtrace::Backtrace> {
+        self.inner().backtrace()
+    }
+}
+
+/// Reference-counted, cloneable counterpart of [`BoxedStackError`].
+///
+/// Clones share one inner error, so every clone reports the same
+/// locations, and [`StackError::find`] sees through them to the concrete
+/// type. Create with [`SharedStackError::new`] or from a `BoxedStackError`,
+/// which moves its error without re-boxing.
+///
+/// ```
+/// use suzunari_error::*;
+///
+/// let shared = SharedStackError::from(BoxedStackError::msg("upload failed"));
+/// let for_metrics = shared.clone();
+/// assert_eq!(
+///     StackReport::from_error(shared).to_string(),
+///     StackReport::from_error(for_metrics).to_string(),
+/// );
+/// ```
+#[derive(Clone)]
+pub struct SharedStackError {
+    inner: Arc<dyn ErasedStackError>,
+}
+
+impl SharedStackError {
+    /// Wraps a concrete `StackError` in a type-erased, shared pointer.
+    #[must_use]
+    pub fn new<T: StackError + Send + Sync + 'static>(inner: T) -> Self {
+        Self {
+            inner: Arc::new(inner),
+        }
+    }
+
+    /// Returns a reference to the inner trait object.
+    #[must_use]
+    pub fn inner(&self) -> &(dyn StackError + Send + Sync) {
+        (*self.inner).as_stack_error()
+    }
+
+    /// Returns the inner error as a `'static` `dyn Error`, which supports
+    /// downcasting to its concrete type.
+    pub(crate) fn inner_error(&self) -> &(dyn Error + 'static) {
+        // `Arc<dyn ErasedStackError>` is itself an `ErasedStackError`, so
+        // deref first to reach the concrete error instead of the `Arc`.
+        (*self.inner).as_error()
+    }
+}
+
+impl From<BoxedStackError> for SharedStackError {
+    fn from(boxed: BoxedStackError) -> Self {
+        let inner: Arc<dyn ErasedStackError> = match boxed.inner {
+            BoxedInner::Erased(inner) => Arc::from(inner),
+            BoxedInner::Dyn(inner) => Arc::new(inner),
+        };
+        Self { inner }
+    }
+}
+
+impl Display for SharedStackError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}", self.inner)
+    }
+}
+
+impl Debug for SharedStackError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{:?}", self.inner)
+    }
+}
+
+impl Error for SharedStackError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.inner.source()
+    }
+}
+
+impl StackError for SharedStackError {
     fn location(&self) -> Location {
         self.inner.location()
     }
     fn type_name(&self) -> &'static str {
         self.inner.type_name()
     }
+    fn module_path(&self) -> Option<&'static str> {
+        self.inner.module_path()
+    }
+    fn severity(&self) -> Severity {
+        self.inner.severity()
+    }
+    fn is_transparent(&self) -> bool {
+        self.inner.is_transparent()
+    }
     fn stack_source(&self) -> Option<&dyn StackError> {
         self.inner.stack_source()
     }
+    fn metadata(&self, visitor: &mut dyn FnMut(&'static str, &dyn Display)) {
+        self.inner.metadata(visitor)
+    }
+    #[cfg(feature = "backtrace")]
+    fn backtrace(&self) -> Option<&std::backtrace::Backtrace> {
+        self.inner.backtrace()
+    }
+}
+
+/// Creates an ad-hoc [`BoxedStackError`] from a message, located at the call site.
+///
+/// Accepts the same arguments as [`format!`](alloc::format), like
+/// `anyhow::anyhow!`, and expands to [`BoxedStackError::msg`]. Named
+/// `stack_error!` because macros share one namespace, so the name
+/// `suzunari_error` is taken by the attribute macro.
+///
+/// ```
+/// use suzunari_error::*;
+///
+/// let name = "config.toml";
+/// let err = stack_error!("failed to parse {}", name);
+/// assert_eq!(err.to_string(), "failed to parse config.toml");
+/// assert_eq!(err.type_name(), "Message");
+/// ```
+#[macro_export]
+macro_rules! stack_error {
+    ($($arg:tt)+) => {
+        $crate::BoxedStackError::msg($crate::__private::format!($($arg)+))
+    };
+}
+
+/// Error created by [`BoxedStackError::msg`].
+#[derive(Debug)]
+struct MessageError {
+    message: Cow<'static, str>,
+    location: Location,
+}
+
+impl Display for MessageError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl Error for MessageError {}
+
+impl StackError for MessageError {
+    fn location(&self) -> Location {
+        self.location
+    }
+    fn type_name(&self) -> &'static str {
+        "Message"
+    }
+}
+
+/// A common non-suzunari error, located where it was converted into a
+/// [`BoxedStackError`].
+///
+/// Renders as the wrapped error itself, under `type_name`, and continues the
+/// chain with the wrapped error's own source.
+struct ForeignError<E> {
+    error: E,
+    type_name: &'static str,
+    location: Location,
+}
+
+impl<E: Error> ForeignError<E> {
+    #[track_caller]
+    fn boxed(error: E, type_name: &'static str) -> BoxedStackError
+    where
+        E: Send + Sync + 'static,
+    {
+        BoxedStackError::new(Self {
+            error,
+            type_name,
+            location: core::panic::Location::caller(),
+        })
+    }
+}
+
+impl<E: Display> Display for ForeignError<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        Display::fmt(&self.error, f)
+    }
+}
+
+impl<E: Debug> Debug for ForeignError<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_struct("ForeignError")
+            .field("error", &self.error)
+            .field("location", &self.location)
+            .finish()
+    }
+}
+
+impl<E: Error> Error for ForeignError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.error.source()
+    }
+}
+
+impl<E: Error> StackError for ForeignError<E> {
+    fn location(&self) -> Location {
+        self.location
+    }
+    fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+}
+
+/// Boxes the error, located where `?` or `.into()` converts it.
+#[cfg(feature = "std")]
+impl From<std::io::Error> for BoxedStackError {
+    #[track_caller]
+    fn from(error: std::io::Error) -> Self {
+        ForeignError::boxed(error, "io::Error")
+    }
+}
+
+/// Boxes the error, located where `?` or `.into()` converts it.
+impl From<core::num::ParseIntError> for BoxedStackError {
+    #[track_caller]
+    fn from(error: core::num::ParseIntError) -> Self {
+        ForeignError::boxed(error, "ParseIntError")
+    }
+}
+
+/// Boxes the error, located where `?` or `.into()` converts it.
+impl From<core::str::Utf8Error> for BoxedStackError {
+    #[track_caller]
+    fn from(error: core::str::Utf8Error) -> Self {
+        ForeignError::boxed(error, "Utf8Error")
+    }
+}
+
+/// `.stack_context(...)` on `Result`: snafu's `.context(...)` for target
+/// errors that are not suzunari-aware.
+///
+/// [`stack_context`](Self::stack_context) builds the target error from the
+/// context selector as `.context(...)` does, then boxes it into a
+/// [`BoxedStackError`] frame located at the call site, so the frame has a
+/// location even when the target type has no location field. The frame is
+/// named after the target type and its causes continue with the original
+/// error.
+///
+/// Prefer `.context(...)` for `#[suzunari_error]` types: they capture their
+/// own location and keep their concrete type. The target error's own
+/// `source()` chain is reported as plain causes, so locations below it are
+/// not shown.
+///
+/// ```
+/// use suzunari_error::*;
+///
+/// // A plain snafu error without a location field.
+/// #[derive(Debug, snafu::Snafu)]
+/// #[snafu(display("config is invalid"))]
+/// struct ConfigError {
+///     source: std::num::ParseIntError,
+/// }
+///
+/// fn load(raw: &str) -> Result<u16, BoxedStackError> {
+///     let port = raw.parse::<u16>().stack_context(ConfigSnafu)?;
+///     Ok(port)
+/// }
+///
+/// let err = load("http").unwrap_err();
+/// assert_eq!(err.type_name(), "ConfigError");
+/// assert_eq!(err.location().file(), file!());
+/// ```
+pub trait StackResultExt<T, E> {
+    /// Applies `context` like snafu's `.context(...)` and boxes the result,
+    /// located at the caller.
+    #[track_caller]
+    fn stack_context<C, E2>(self, context: C) -> core::result::Result<T, BoxedStackError>
+    where
+        C: snafu::IntoError<E2, Source = E>,
+        E2: Error + snafu::ErrorCompat + Send + Sync + 'static;
+}
+
+impl<T, E: Error> StackResultExt<T, E> for core::result::Result<T, E> {
+    #[track_caller]
+    fn stack_context<C, E2>(self, context: C) -> core::result::Result<T, BoxedStackError>
+    where
+        C: snafu::IntoError<E2, Source = E>,
+        E2: Error + snafu::ErrorCompat + Send + Sync + 'static,
+    {
+        // A `match` rather than `map_err`: a closure would not forward the
+        // caller's location to `ForeignError::boxed`.
+        match self {
+            Ok(value) => Ok(value),
+            Err(error) => Err(ForeignError::boxed(
+                context.into_error(error),
+                short_type_name::<E2>(),
+            )),
+        }
+    }
+}
+
+/// Returns the last path segment of `T`'s name, without generic arguments.
+fn short_type_name<T: ?Sized>() -> &'static str {
+    let name = core::any::type_name::<T>();
+    let name = name.split('<').next().unwrap_or(name);
+    name.rsplit("::").next().unwrap_or(name)
 }
 
 impl From<Box<dyn StackError + Send + Sync>> for BoxedStackError {
     fn from(inner: Box<dyn StackError + Send + Sync>) -> Self {
-        Self { inner }
+        Self::new_boxed(inner)
     }
 }
 
@@ -253,6 +705,24 @@ mod tests {
         assert_eq!(inner.type_name(), "TestError");
     }
 
+    #[test]
+    fn test_borrow_as_dyn_stack_error() {
+        fn describe(error: &dyn StackError) -> (&'static str, u32) {
+            (error.type_name(), error.location().line())
+        }
+        let test_error = TestSnafu {
+            message: "borrowed",
+        }
+        .build();
+        let line = test_error.location().line();
+        let error = BoxedStackError::new(test_error);
+
+        // Both borrows leave `error` usable afterwards.
+        assert_eq!(describe(error.inner()), ("TestError", line));
+        assert_eq!(describe(&error), ("TestError", line));
+        assert_eq!(format!("{error}"), "Test error: borrowed");
+    }
+
     #[test]
     fn test_into_inner_round_trip() {
         let test_error = TestSnafu {
@@ -273,4 +743,112 @@ mod tests {
         let boxed_again: BoxedStackError = inner.into();
         assert_eq!(boxed_again.location().line(), original_line);
     }
+
+    #[test]
+    fn test_new_boxed_keeps_trait_object_box() {
+        let test_error = TestSnafu { message: "boxed" }.build();
+        let original_line = test_error.location().line();
+        let inner: Box<dyn StackError + Send + Sync> = Box::new(test_error);
+        let address = &*inner as *const _ as *const ();
+
+        // Both paths store the box itself rather than boxing it again.
+        let via_new_boxed = BoxedStackError::new_boxed(inner);
+        assert_eq!(via_new_boxed.type_name(), "TestError");
+        assert_eq!(via_new_boxed.location().line(), original_line);
+        assert_eq!(format!("{via_new_boxed}"), "Test error: boxed");
+        let inner = via_new_boxed.into_inner();
+        assert_eq!(&*inner as *const _ as *const (), address);
+
+        let via_from = BoxedStackError::from(inner);
+        assert_eq!(via_from.type_name(), "TestError");
+        assert_eq!(via_from.location().line(), original_line);
+        let shared = SharedStackError::from(via_from);
+        assert_eq!(shared.type_name(), "TestError");
+        assert_eq!(format!("{shared}"), "Test error: boxed");
+    }
+
+    #[test]
+    fn test_msg_static_str() {
+        let error = BoxedStackError::msg("static failure");
+        let line = line!() - 1;
+
+        assert_eq!(error.location().file(), file!());
+        assert_eq!(error.location().line(), line);
+        assert_eq!(error.type_name(), "Message");
+        assert_eq!(format!("{error}"), "static failure");
+        assert!(error.source().is_none());
+    }
+
+    #[test]
+    fn test_msg_string_renders_in_report() {
+        let error = BoxedStackError::msg(format!("code {}", 42));
+        let location = error.location();
+
+        assert_eq!(
+            format!("{}", crate::StackReport::from(error)),
+            format!("Error: Message: code 42, at {location}")
+        );
+        assert_eq!(location.line(), line!() - 7);
+    }
+
+    #[test]
+    fn test_same_site() {
+        fn build(message: &str) -> BoxedStackError {
+            BoxedStackError::new(TestSnafu { message }.build())
+        }
+        let first = build("first");
+        let second = build("second");
+        assert!(first.same_site(&second));
+        assert_eq!(first.fingerprint(), second.fingerprint());
+        assert_eq!(first.fingerprint().0, "TestError");
+
+        let elsewhere = BoxedStackError::new(TestSnafu { message: "first" }.build());
+        assert!(!first.same_site(&elsewhere));
+        assert_ne!(first.fingerprint(), elsewhere.fingerprint());
+    }
+
+    #[test]
+    fn test_shared_clone_renders_same_report() {
+        let inner = BoxedStackError::new(TestSnafu { message: "inner" }.build());
+        let wrapper = WrapperTestSnafu { message: "outer" }.into_error(inner);
+        let shared = SharedStackError::new(wrapper);
+        let clone = shared.clone();
+
+        assert_eq!(clone.location(), shared.location());
+        assert_eq!(clone.depth(), 1);
+        assert_eq!(
+            format!("{}", crate::StackReport::from_error(clone)),
+            format!("{}", crate::StackReport::from_error(shared.clone())),
+        );
+        assert!(shared.find::<TestError>().is_some());
+        assert!(shared.find::<WrapperTestError>().is_some());
+    }
+
+    #[test]
+    fn test_shared_from_boxed_keeps_inner_type() {
+        let boxed = BoxedStackError::new(TestSnafu { message: "moved" }.build());
+        let location = boxed.location();
+        let shared = SharedStackError::from(boxed);
+        assert_eq!(shared.location(), location);
+        assert_eq!(shared.type_name(), "TestError");
+        assert_eq!(format!("{shared}"), "Test error: moved");
+        assert!(shared.find::<TestError>().is_some());
+    }
+
+    #[test]
+    fn test_stack_error_macro() {
+        let name = "config.toml";
+        let literal = crate::stack_error!("plain message");
+        let formatted = crate::stack_error!("failed to parse {} ({name})", name);
+        let line = line!() - 1;
+
+        assert_eq!(format!("{literal}"), "plain message");
+        assert_eq!(
+            format!("{formatted}"),
+            "failed to parse config.toml (config.toml)"
+        );
+        assert_eq!(formatted.location().file(), file!());
+        assert_eq!(formatted.location().line(), line);
+        assert_eq!(formatted.type_name(), "Message");
+    }
 }
@@ -117,6 +117,27 @@ impl<E: Debug + Display> DisplayError<E> {
         }
     }
 
+    /// Transforms the wrapped value with `f`, e.g., to enrich it before use.
+    ///
+    /// The result behaves like [`DisplayError::new`]: `source()` returns `None`,
+    /// even if this value was created by `#[suzu(from)]` with source chain
+    /// delegation, because the resolver is tied to the original type `E`.
+    ///
+    /// ```
+    /// use suzunari_error::DisplayError;
+    ///
+    /// let wrapped = DisplayError::new(404).map(|code| format!("status {code}"));
+    /// assert_eq!(wrapped.to_string(), "status 404");
+    /// ```
+    #[must_use]
+    pub fn map<F, U>(self, f: F) -> DisplayError<U>
+    where
+        F: FnOnce(E) -> U,
+        U: Debug + Display,
+    {
+        DisplayError::new(f(self.inner))
+    }
+
     /// Internal constructor with an explicit `get_source` resolver.
     /// Use [`DisplayError::new`] in application code.
     pub(crate) fn with_get_source(
@@ -137,6 +158,14 @@ impl<E> DisplayError<E> {
         &self.inner
     }
 
+    /// Returns a mutable reference to the wrapped value.
+    ///
+    /// The `source()` behavior chosen at construction is kept.
+    #[must_use]
+    pub fn inner_mut(&mut self) -> &mut E {
+        &mut self.inner
+    }
+
     /// Unwraps and returns the inner value.
     #[must_use]
     pub fn into_inner(self) -> E {
@@ -197,7 +226,40 @@ impl<E: Debug + Display> Error for DisplayError<E> {
     }
 }
 
-// No From impl — intentionally omitted to prevent implicit .into() conversions.
+// No From impl by default — intentionally omitted to prevent implicit
+// .into() conversions. The `display-error-from` feature opts in.
+
+/// Wraps any `Debug + Display` value via `.into()` or `?`.
+///
+/// Only available with the `display-error-from` feature. It is off by default
+/// because the conversion is a footgun:
+///
+/// - `?` silently wraps *any* `Debug + Display` value, including types that
+///   already implement `Error`, and the result always returns `None` from
+///   `source()` (like [`DisplayError::new`]). The wrapped error's source
+///   chain is lost; use `#[suzu(from)]` to preserve it.
+/// - Cargo features are unified, so enabling this in one crate enables it for
+///   every crate in the build that depends on `suzunari-error`.
+///
+/// ```
+/// # #[cfg(feature = "display-error-from")]
+/// # {
+/// use suzunari_error::DisplayError;
+///
+/// fn parse(input: &str) -> Result<u8, DisplayError<&'static str>> {
+///     let first = input.bytes().next().ok_or("empty input")?;
+///     Ok(first)
+/// }
+///
+/// assert_eq!(parse("").unwrap_err().to_string(), "empty input");
+/// # }
+/// ```
+#[cfg(feature = "display-error-from")]
+impl<E: Debug + Display> From<E> for DisplayError<E> {
+    fn from(error: E) -> Self {
+        Self::new(error)
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -233,6 +295,13 @@ mod tests {
         assert_eq!(wrapped.inner().message, "ref access");
     }
 
+    #[test]
+    fn test_inner_mut() {
+        let mut wrapped = DisplayError::new(FakeLibError { message: "before" });
+        wrapped.inner_mut().message = "after";
+        assert_eq!(wrapped.inner().message, "after");
+    }
+
     #[test]
     fn test_clone() {
         #[derive(Clone)]
@@ -276,6 +345,22 @@ mod tests {
         assert!(err.source().is_none());
     }
 
+    #[cfg(feature = "display-error-from")]
+    #[test]
+    fn test_from_via_question_mark() {
+        fn fallible() -> Result<(), FakeLibError> {
+            Err(FakeLibError { message: "boom" })
+        }
+        fn wrapped() -> Result<(), DisplayError<FakeLibError>> {
+            fallible()?;
+            Ok(())
+        }
+        let err = wrapped().unwrap_err();
+        assert_eq!(err.inner().message, "boom");
+        let err: &dyn Error = &err;
+        assert!(err.source().is_none());
+    }
+
     #[test]
     fn test_partial_eq() {
         let a = DisplayError::new(42);
@@ -376,6 +461,57 @@ mod tests {
             assert_eq!(alloc::format!("{source}"), "inner");
         }
 
+        #[test]
+        fn test_map_transforms_inner_and_delegates_display() {
+            struct Annotated(alloc::string::String);
+            impl Display for Annotated {
+                fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                    f.write_str(&self.0)
+                }
+            }
+            impl Debug for Annotated {
+                fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                    write!(f, "Annotated({})", self.0)
+                }
+            }
+
+            let wrapped = DisplayError::new(FakeLibError { message: "boom" })
+                .map(|e| Annotated(alloc::format!("lib: {e}")));
+            assert_eq!(alloc::format!("{wrapped}"), "lib: boom");
+            assert_eq!(alloc::format!("{wrapped:?}"), "Annotated(lib: boom)");
+        }
+
+        #[test]
+        fn test_map_drops_source_delegation() {
+            #[derive(Debug)]
+            struct InnerError;
+            impl Display for InnerError {
+                fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                    f.write_str("inner")
+                }
+            }
+            impl Error for InnerError {}
+
+            #[derive(Debug)]
+            struct OuterError(InnerError);
+            impl Display for OuterError {
+                fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                    f.write_str("outer")
+                }
+            }
+            impl Error for OuterError {
+                fn source(&self) -> Option<&(dyn Error + 'static)> {
+                    Some(&self.0)
+                }
+            }
+
+            let wrapped = DisplayError::with_get_source(OuterError(InnerError), |e| e.source());
+            let mapped = wrapped.map(|e| alloc::format!("{e}"));
+            let err: &dyn Error = &mapped;
+            assert!(err.source().is_none());
+            assert_eq!(mapped.inner(), "outer");
+        }
+
         #[test]
         fn test_display_delegates() {
             let wrapped = DisplayError::new(FakeLibError {
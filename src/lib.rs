@@ -19,21 +19,38 @@
 //! }
 //! ```
 //!
+//! [`prelude`] is a narrower import with just the commonly used items.
+//!
 //! # Key Types
 //!
 //! - [`Location`] — Type alias for `&'static core::panic::Location<'static>`; captures call-site file/line/column
-//! - [`StackError`] — Extends `Error` with `location()`, `type_name()`, `stack_source()`, and `depth()`
+//! - [`StackError`] — Extends `Error` with `location()`, `type_name()`, `module_path()`, `stack_source()`, and `stack_depth()` / `total_depth()`
 //! - [`StackReport`] — Formats a `StackError` chain for display with location info
+//! - [`MultiStackReport`] — Reports several independent errors together (requires `alloc`)
+//! - [`Link`] / [`Links`] — The frames of a report as data, via [`StackReport::links`], for custom renderers
+//! - [`ReportStyle`] — Custom report layouts, applied with [`StackReport::with_style`]
+//! - [`ResultReportExt`] — `.into_report()` on `Result<(), E>`, an annotation-free alternative to `.into()`
 //! - [`BoxedStackError`] — Type-erased `StackError` wrapper (requires `alloc`)
+//! - [`SharedStackError`] — Cloneable, reference-counted counterpart of `BoxedStackError` (requires `alloc`)
+//! - [`StackResultExt`] — `.stack_context(...)`, a located `.context(...)` for error types without a location field (requires `alloc`)
+//! - [`stack_error!`] — Builds an ad-hoc `BoxedStackError` from a format string (requires `alloc`)
+//! - [`SuzunariWhatever`] / [`whatever_stack!`] — Stringly-typed error for prototypes, also built by `.whatever_context(...)` (requires `alloc`)
+//! - [`ensure_stack!`] — Returns early with an error built from a struct literal, located at the call site
+//! - [`Severity`] — Severity level of an error, declared with `#[suzu(severity = ...)]`
+//! - [`RelocateStackError`] — `.relocate(location)` / `.relocate_here()` to move an error to the site it describes
+//! - [`AsLocation`] — Conversion from a custom location field type to [`Location`]
+//! - [`SourceSpan`] — A start `Location` with an optional end position, usable as a location field
 //! - [`DisplayError`] — Adapter for `Debug + Display` types that don't implement `Error`
 //!
 //! # Feature Flags
 //!
 //! | Feature | Default | Provides |
 //! |---------|---------|----------|
-//! | `std`   | Yes     | `alloc` + [`StackReport`]'s [`Termination`](std::process::Termination) impl + [`#[report]`](macro@report) macro |
-//! | `alloc` | via `std` | [`BoxedStackError`] + `From<T> for BoxedStackError` generation |
-//! | _(none)_ | —      | Core-only: [`Location`], [`StackError`], [`StackReport`] (formatting only), [`DisplayError`] |
+//! | `std`   | Yes     | `alloc` + [`StackReport`]'s [`Termination`](std::process::Termination) impl + [`#[report]`](macro@report) macro + [`LocationUriScheme`] links |
+//! | `alloc` | via `std` | [`BoxedStackError`] + [`SharedStackError`] + `From<T> for BoxedStackError` generation + [`stack_error!`] + [`SuzunariWhatever`] / [`whatever_stack!`] + [`StackResultExt`] + [`MultiStackReport`] + [`StackReport::map_locations`] + [`StackReport::dedup_adjacent`] + `StackError::own_message()` |
+//! | `display-error-from` | No | `From<E> for DisplayError<E>` (opt-in; see [`DisplayError`]) |
+//! | `backtrace` | No | `std` + `StackError::backtrace()`, `#[suzu(backtrace)]`, and a `Backtrace:` section in [`StackReport`] |
+//! | _(none)_ | —      | Core-only: [`Location`], [`StackError`], [`StackReport`] (formatting only), [`DisplayError`], [`ensure_stack!`] |
 //!
 //! # `#[suzu(...)]` Attribute
 //!
@@ -41,14 +58,40 @@
 //! It is a superset of `#[snafu(...)]` — standard snafu keywords (`display`, `source`,
 //! `visibility`, etc.) pass through as-is, plus suzunari extensions are available.
 //! `#[snafu(...)]` also works, but `#[suzu(...)]` is preferred for consistency.
+//! The injected `location` field can be referenced in `display(...)` like any
+//! other field, e.g. `display("lookup failed at {location}")`.
 //!
 //! Suzunari extensions:
 //!
 //! - **`from`** (field-level) — wraps a field type in [`DisplayError<T>`] and generates
 //!   a `source(from(...))` conversion that automatically preserves the `Error::source()`
-//!   chain when the wrapped type implements `Error`
+//!   chain when the wrapped type implements `Error`; `from(boxed)` stores a `StackError`
+//!   source as [`BoxedStackError`], and `from(T, with = f)` converts a `T` source with `f`
+//!   instead of wrapping it
 //! - **`location`** (field-level) — marks a field as the location field with a custom name;
-//!   converts to `#[stack(location)]` + `#[snafu(implicit)]`
+//!   converts to `#[stack(location)]` + `#[snafu(implicit)]`; custom location types
+//!   work through [`AsLocation`]
+//! - **`meta`** (field-level) — exposes a `Display` field as key-value metadata via
+//!   [`StackError::metadata`]; converts to `#[stack(meta)]`
+//! - **`backtrace`** (field-level, `backtrace` feature) — exposes a `Backtrace` or
+//!   `Option<Backtrace>` field via `StackError::backtrace()`; converts to
+//!   `#[stack(backtrace)]` + `#[snafu(backtrace)]`
+//! - **`rename`** (type/variant-level) — overrides the [`StackError::type_name`] string
+//!   with `rename = "..."`; on a variant this replaces only the `Variant` part of
+//!   `Enum::Variant`, and `rename(full = "...")` replaces the whole string; converts to
+//!   `#[stack(rename ...)]`
+//! - **`severity`** (type/variant-level) — sets [`StackError::severity`], e.g.
+//!   `severity = critical`; a variant-level severity overrides the type-level one, and
+//!   unannotated errors are [`Severity::Error`]; converts to `#[stack(severity = ...)]`
+//! - **`qualified`** (type-level) — prefixes [`StackError::type_name`] with the defining
+//!   module's path (`app::db::QueryError` instead of `QueryError`) to tell apart
+//!   same-named errors from different modules; converts to `#[stack(qualified)]`
+//! - **`crate`** (type-level) — path to this crate when it is renamed in `Cargo.toml` or
+//!   reached through a re-exporting facade, e.g. `crate = my_facade::errors`;
+//!   converts to `#[stack(crate = ...)]`
+//! - **`skip_location`** (type/variant-level) — injects no location field; `location()`
+//!   returns the source's location instead, so the source must be a `StackError`;
+//!   converts to `#[stack(skip_location)]`
 //!
 //! # Known Limitations
 //!
@@ -70,7 +113,10 @@ extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
 
+pub mod prelude;
+
 mod display_error;
+mod source_span;
 mod stack_error;
 mod stack_report;
 
@@ -79,9 +125,17 @@ pub mod __private;
 
 #[cfg(feature = "alloc")]
 mod boxed_stack_error;
+#[cfg(feature = "alloc")]
+mod multi_stack_report;
+#[cfg(feature = "alloc")]
+mod whatever;
 
 #[cfg(feature = "alloc")]
-pub use boxed_stack_error::BoxedStackError;
+pub use boxed_stack_error::{BoxedStackError, SharedStackError, StackResultExt};
+#[cfg(feature = "alloc")]
+pub use multi_stack_report::MultiStackReport;
+#[cfg(feature = "alloc")]
+pub use whatever::SuzunariWhatever;
 /// Type alias for `&'static core::panic::Location<'static>`.
 ///
 /// Used as the location field type in error structs generated by [`#[suzunari_error]`](macro@suzunari_error).
@@ -90,8 +144,14 @@ pub use boxed_stack_error::BoxedStackError;
 pub type Location = &'static core::panic::Location<'static>;
 
 pub use display_error::DisplayError;
-pub use stack_error::StackError;
-pub use stack_report::StackReport;
+pub use source_span::SourceSpan;
+pub use stack_error::{AsLocation, Locations, RelocateStackError, Severity, StackError};
+#[cfg(feature = "std")]
+pub use stack_report::LocationUriScheme;
+pub use stack_report::{
+    DefaultReportStyle, IndexStyle, Link, Links, ReportStyle, ResultReportExt, StackReport,
+    StackReportStyle, StyledReport,
+};
 
 // Re-export snafu so downstream crates don't need it as a direct dependency.
 // The proc-macro generates `#[snafu(crate_root(::suzunari_error::snafu))]`
@@ -0,0 +1,134 @@
+use alloc::vec::Vec;
+
+use crate::{StackError, StackReport};
+use core::fmt::{Debug, Display, Formatter, Result};
+
+#[cfg(feature = "std")]
+use std::io::{Write, stderr};
+#[cfg(feature = "std")]
+use std::process::{ExitCode, Termination};
+
+/// Reports several independent errors together, e.g., every failed field of
+/// a validation pass.
+///
+/// Renders an `N errors occurred:` header followed by one numbered
+/// [`StackReport`] section per error, separated by blank lines. An empty
+/// report renders nothing. Like `StackReport`, `Debug` delegates to
+/// `Display`, and with the `std` feature it implements [`Termination`],
+/// exiting with failure when any error was collected.
+///
+/// ```
+/// use suzunari_error::*;
+///
+/// #[suzunari_error]
+/// #[suzu(display("`{field}` is required"))]
+/// struct MissingFieldError {
+///     field: &'static str,
+/// }
+///
+/// let report: MultiStackReport<_> = ["name", "email"]
+///     .into_iter()
+///     .map(|field| MissingFieldSnafu { field }.build())
+///     .collect();
+/// let output = report.to_string();
+/// assert!(output.starts_with("2 errors occurred:\n\n[1] Error: MissingFieldError: `name` is required, at "));
+/// assert!(output.contains("\n\n[2] Error: MissingFieldError: `email` is required, at "));
+/// ```
+pub struct MultiStackReport<E> {
+    errors: Vec<E>,
+}
+
+impl<E: StackError> MultiStackReport<E> {
+    /// Creates an empty report.
+    #[must_use]
+    pub fn new() -> Self {
+        Self { errors: Vec::new() }
+    }
+
+    /// Creates a report from the errors of `errors`, in order.
+    #[must_use]
+    pub fn from_errors<I: IntoIterator<Item = E>>(errors: I) -> Self {
+        errors.into_iter().collect()
+    }
+
+    /// Appends `error` to the report.
+    pub fn push(&mut self, error: E) {
+        self.errors.push(error);
+    }
+
+    /// Returns the number of collected errors.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.errors.len()
+    }
+
+    /// Returns `true` if no error was collected.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Returns the collected errors.
+    #[must_use]
+    pub fn errors(&self) -> &[E] {
+        &self.errors
+    }
+
+    /// Returns `Ok(())` if no error was collected, otherwise `Err(self)`,
+    /// for returning early after a validation pass.
+    pub fn into_result(self) -> core::result::Result<(), Self> {
+        if self.is_empty() { Ok(()) } else { Err(self) }
+    }
+}
+
+impl<E: StackError> Default for MultiStackReport<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E: StackError> FromIterator<E> for MultiStackReport<E> {
+    fn from_iter<I: IntoIterator<Item = E>>(errors: I) -> Self {
+        Self {
+            errors: errors.into_iter().collect(),
+        }
+    }
+}
+
+impl<E: StackError> Extend<E> for MultiStackReport<E> {
+    fn extend<I: IntoIterator<Item = E>>(&mut self, errors: I) {
+        self.errors.extend(errors);
+    }
+}
+
+impl<E: StackError> Display for MultiStackReport<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self.errors.len() {
+            0 => return Ok(()),
+            1 => f.write_str("1 error occurred:")?,
+            n => write!(f, "{n} errors occurred:")?,
+        }
+        for (i, error) in self.errors.iter().enumerate() {
+            write!(f, "\n\n[{}] {}", i + 1, StackReport::from_error(error))?;
+        }
+        Ok(())
+    }
+}
+
+impl<E: StackError> Debug for MultiStackReport<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        Display::fmt(self, f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: StackError> Termination for MultiStackReport<E> {
+    fn report(self) -> ExitCode {
+        if self.is_empty() {
+            return ExitCode::SUCCESS;
+        }
+        // Same contract as StackReport: ignore write errors, add the newline here.
+        let _ = Write::write_fmt(&mut stderr(), format_args!("{self}\n"));
+        ExitCode::FAILURE
+    }
+}
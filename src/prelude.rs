@@ -0,0 +1,32 @@
+//! The commonly used items, for `use suzunari_error::prelude::*;`.
+//!
+//! A narrower alternative to `use suzunari_error::*`: it brings in the
+//! macros, the core types, and snafu's context extension traits, but not
+//! the less common types (report styles, `Links`, `SourceSpan`, ...) or the
+//! `snafu` module itself. `#[report]` is left out as well; write it as
+//! `#[suzunari_error::report]`.
+//!
+//! ```
+//! use suzunari_error::prelude::*;
+//!
+//! #[suzunari_error]
+//! #[suzu(display("config not found"))]
+//! struct ConfigError {
+//!     source: std::io::Error,
+//! }
+//!
+//! fn load() -> Result<Vec<u8>, ConfigError> {
+//!     std::fs::read("/nonexistent/config.toml").context(ConfigSnafu)
+//! }
+//!
+//! let report = StackReport::from_error(load().unwrap_err()).to_string();
+//! assert!(report.starts_with("Error: ConfigError: config not found, at "));
+//! ```
+
+#[cfg(feature = "alloc")]
+pub use crate::{
+    BoxedStackError, SharedStackError, StackResultExt, SuzunariWhatever, stack_error,
+    whatever_stack,
+};
+pub use crate::{DisplayError, Location, Severity, StackError, StackReport, ensure_stack};
+pub use crate::{OptionExt, ResultExt, ensure, suzunari_error};
@@ -0,0 +1,135 @@
+use crate::{AsLocation, Location};
+use core::fmt::{Display, Formatter};
+
+/// A source region: a start [`Location`] plus an optional end position.
+///
+/// `#[track_caller]` only yields a single point, so the end is unset unless
+/// supplied with [`with_end`](Self::with_end), e.g. by a macro or tool that
+/// knows the extent of the construct. Implements [`AsLocation`] and
+/// [`snafu::GenerateImplicitData`], so it can replace `Location` as the type
+/// of a `#[suzu(location)]` field; [`StackError::location`](crate::StackError::location)
+/// then reports the start.
+///
+/// ```
+/// use suzunari_error::*;
+///
+/// #[suzunari_error]
+/// #[suzu(display("bad config block"))]
+/// struct ConfigError {
+///     #[suzu(location)]
+///     span: SourceSpan,
+/// }
+///
+/// let err = ConfigSnafu.build();
+/// assert_eq!(err.location(), err.span.start());
+/// assert_eq!(err.span.end_line(), None);
+///
+/// let span = err.span.with_end(err.span.start().line() + 3, 2);
+/// assert_eq!(span.end_column(), Some(2));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SourceSpan {
+    start: Location,
+    end: Option<(u32, u32)>,
+}
+
+impl SourceSpan {
+    /// Creates a single-point span starting at `start`.
+    #[must_use]
+    pub const fn new(start: Location) -> Self {
+        Self { start, end: None }
+    }
+
+    /// Creates a single-point span at the caller's location.
+    #[must_use]
+    #[track_caller]
+    pub const fn caller() -> Self {
+        Self::new(core::panic::Location::caller())
+    }
+
+    /// Sets the end position (1-based line and column, like [`Location`]).
+    #[must_use]
+    pub const fn with_end(self, line: u32, column: u32) -> Self {
+        Self {
+            start: self.start,
+            end: Some((line, column)),
+        }
+    }
+
+    /// Returns the start of the span.
+    #[must_use]
+    pub const fn start(&self) -> Location {
+        self.start
+    }
+
+    /// Returns the end line, if an end was set.
+    #[must_use]
+    pub const fn end_line(&self) -> Option<u32> {
+        match self.end {
+            Some((line, _)) => Some(line),
+            None => None,
+        }
+    }
+
+    /// Returns the end column, if an end was set.
+    #[must_use]
+    pub const fn end_column(&self) -> Option<u32> {
+        match self.end {
+            Some((_, column)) => Some(column),
+            None => None,
+        }
+    }
+}
+
+/// Formats as `file:line:column`, followed by `-end_line:end_column` when an
+/// end is set.
+impl Display for SourceSpan {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.start)?;
+        match self.end {
+            Some((line, column)) => write!(f, "-{line}:{column}"),
+            None => Ok(()),
+        }
+    }
+}
+
+impl AsLocation for SourceSpan {
+    fn as_location(&self) -> Location {
+        self.start
+    }
+}
+
+impl snafu::GenerateImplicitData for SourceSpan {
+    #[track_caller]
+    fn generate() -> Self {
+        Self::caller()
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn test_single_point() {
+        let span = SourceSpan::caller();
+        let line = line!() - 1;
+        assert_eq!(span.start().line(), line);
+        assert_eq!(span.end_line(), None);
+        assert_eq!(span.end_column(), None);
+        assert_eq!(span.as_location(), span.start());
+        assert_eq!(format!("{span}"), format!("{}", span.start()));
+    }
+
+    #[test]
+    fn test_with_end() {
+        let start = core::panic::Location::caller();
+        let span = SourceSpan::new(start).with_end(start.line() + 2, 7);
+        assert_eq!(span.start(), start);
+        assert_eq!(span.end_line(), Some(start.line() + 2));
+        assert_eq!(span.end_column(), Some(7));
+        assert_eq!(format!("{span}"), format!("{start}-{}:7", start.line() + 2));
+        assert_ne!(span, SourceSpan::new(start));
+    }
+}
@@ -1,5 +1,7 @@
 use crate::Location;
 use core::error::Error;
+use core::fmt::{Debug, Display, Formatter};
+use core::iter::FusedIterator;
 
 /// Error trait extension that adds source code location tracking.
 ///
@@ -89,13 +91,352 @@ pub trait StackError: Error {
         None
     }
 
+    /// Returns whether the chain continues with a `StackError`, i.e.,
+    /// [`stack_source`](StackError::stack_source) is `Some`.
+    ///
+    /// `StackReport` prints such a cause with its type name and location.
+    #[must_use]
+    fn has_stack_source(&self) -> bool {
+        self.stack_source().is_some()
+    }
+
+    /// Returns whether the chain continues with a plain `Error`, i.e.,
+    /// `Error::source()` is `Some` but `stack_source()` is `None`.
+    ///
+    /// This is where `StackReport` switches from located frames to plain
+    /// `source()` causes. At most one of this and
+    /// [`has_stack_source`](StackError::has_stack_source) is `true`; both
+    /// are `false` for the last error in the chain.
+    ///
+    /// ```
+    /// use suzunari_error::*;
+    ///
+    /// #[suzunari_error]
+    /// #[suzu(display("read failed"))]
+    /// struct ReadError {
+    ///     source: std::io::Error,
+    /// }
+    ///
+    /// let err = Err::<(), _>(std::io::Error::other("gone"))
+    ///     .context(ReadSnafu)
+    ///     .unwrap_err();
+    /// assert!(err.has_plain_source());
+    /// assert!(!err.has_stack_source());
+    /// ```
+    #[must_use]
+    fn has_plain_source(&self) -> bool {
+        self.stack_source().is_none() && self.source().is_some()
+    }
+
+    /// Returns the module path where this error type is defined, as
+    /// produced by `module_path!()` (e.g., `"my_app::db"`).
+    ///
+    /// The derive macro generates this as a `&'static str` constant, so it
+    /// costs nothing when unused. It names the module of the error type, not
+    /// of the code that constructed the error: unlike the location, a module
+    /// path cannot be forwarded through `#[track_caller]`. The default
+    /// implementation returns `None`.
+    #[must_use]
+    fn module_path(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// Returns how severe this error is, e.g., for choosing a log level.
+    ///
+    /// The derive macro generates this from `#[suzu(severity = ...)]` (or
+    /// `#[stack(severity = ...)]`) on the type or variant. For enums, a
+    /// variant-level severity overrides the type-level one, and variants
+    /// without either use the default. The default implementation returns
+    /// [`Severity::Error`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use suzunari_error::*;
+    ///
+    /// #[suzunari_error]
+    /// #[suzu(severity = warning)]
+    /// enum CacheError {
+    ///     #[suzu(display("cache miss"))]
+    ///     Miss,
+    ///     #[suzu(severity = critical, display("cache corrupted"))]
+    ///     Corrupted,
+    /// }
+    ///
+    /// assert_eq!(MissSnafu.build().severity(), Severity::Warning);
+    /// assert_eq!(CorruptedSnafu.build().severity(), Severity::Critical);
+    /// ```
+    #[must_use]
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    /// Returns whether this frame only forwards its source and should be
+    /// left out of reports.
+    ///
+    /// The derive macro returns `true` for types and variants marked
+    /// `#[suzu(transparent)]` (snafu's `transparent`, which delegates
+    /// `Display` and `Error::source` to the source). `StackReport` skips
+    /// such frames but keeps walking through them, so the wrapper does not
+    /// appear as its own `Caused by` line.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use suzunari_error::*;
+    ///
+    /// #[suzunari_error]
+    /// #[suzu(display("disk full"))]
+    /// struct DiskError {}
+    ///
+    /// #[suzunari_error]
+    /// enum StoreError {
+    ///     #[suzu(transparent)]
+    ///     Disk { source: DiskError },
+    /// }
+    ///
+    /// fn store() -> Result<(), StoreError> {
+    ///     Err(DiskSnafu.build())?;
+    ///     Ok(())
+    /// }
+    ///
+    /// assert!(store().unwrap_err().is_transparent());
+    /// assert!(!DiskSnafu.build().is_transparent());
+    /// ```
+    #[must_use]
+    fn is_transparent(&self) -> bool {
+        false
+    }
+
+    /// Returns this error's own `Display` message, without its sources or
+    /// location.
+    ///
+    /// Equivalent to `self.to_string()`; provided so custom reports can take
+    /// one frame's text without reaching for `Debug`, which for snafu errors
+    /// includes every nested field. Core-only users can format the error
+    /// with `Display` directly.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    fn own_message(&self) -> alloc::string::String {
+        alloc::format!("{self}")
+    }
+
+    /// Passes each key-value metadata entry of this error to `visitor`.
+    ///
+    /// Metadata is structured context (e.g., `user_id`, `request_id`) that
+    /// `StackReport` renders after the location of the frame. Only this
+    /// error's own entries are visited, not those of its sources.
+    ///
+    /// The derive macro generates this for fields marked with
+    /// `#[suzu(meta)]` (or `#[stack(meta)]`), using the field name as the key.
+    /// The default implementation visits nothing.
+    ///
+    /// A visitor is used instead of returning a slice so that implementations
+    /// can expose borrowed fields without allocating.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use suzunari_error::*;
+    ///
+    /// #[suzunari_error]
+    /// #[suzu(display("request failed"))]
+    /// struct RequestError {
+    ///     #[suzu(meta)]
+    ///     request_id: u64,
+    /// }
+    ///
+    /// let err = RequestSnafu { request_id: 42u64 }.build();
+    /// let mut entries = Vec::new();
+    /// err.metadata(&mut |key, value| entries.push(format!("{key}={value}")));
+    /// assert_eq!(entries, ["request_id=42"]);
+    /// ```
+    fn metadata(&self, visitor: &mut dyn FnMut(&'static str, &dyn Display)) {
+        let _ = visitor;
+    }
+
+    /// Returns the backtrace captured when this error was constructed, if any.
+    ///
+    /// The derive macro generates this for a field marked with
+    /// `#[suzu(backtrace)]` (or `#[stack(backtrace)]`), or for a field named
+    /// `backtrace`, matching snafu's convention. The field may be a
+    /// `Backtrace` (always captured) or an `Option<Backtrace>` (captured only
+    /// when `RUST_BACKTRACE` or `RUST_LIB_BACKTRACE` is enabled). The default
+    /// implementation returns `None`.
+    ///
+    /// `StackReport` prints the backtrace of the deepest error in the chain
+    /// that has a captured one.
+    #[cfg(feature = "backtrace")]
+    #[must_use]
+    fn backtrace(&self) -> Option<&std::backtrace::Backtrace> {
+        None
+    }
+
+    /// Returns an iterator over the location of `self` and of each
+    /// `stack_source()` link, outermost first.
+    ///
+    /// Only the StackError phase of the chain is visited; plain `Error`
+    /// sources carry no location. Useful for telemetry that records where
+    /// each frame was created without the messages.
+    #[must_use]
+    fn locations(&self) -> Locations<'_> {
+        Locations {
+            first: Some(self.location()),
+            next: self.stack_source(),
+        }
+    }
+
+    /// Returns the first error in the chain, starting with `self`, whose
+    /// concrete type is `T`.
+    ///
+    /// Walks the `Error::source()` chain, which contains every
+    /// `stack_source()` link and can be downcast because its links are
+    /// `'static`. Errors boxed in a [`BoxedStackError`](crate::BoxedStackError)
+    /// or [`SharedStackError`](crate::SharedStackError) are matched by their
+    /// inner type as well.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use suzunari_error::*;
+    ///
+    /// #[suzunari_error]
+    /// #[suzu(display("timed out"))]
+    /// struct TimeoutError {}
+    ///
+    /// #[suzunari_error]
+    /// #[suzu(display("request failed"))]
+    /// struct RequestError {
+    ///     source: TimeoutError,
+    /// }
+    ///
+    /// let err = Err::<(), _>(TimeoutSnafu.build())
+    ///     .context(RequestSnafu)
+    ///     .unwrap_err();
+    /// assert!(err.find::<TimeoutError>().is_some());
+    /// ```
+    #[must_use]
+    fn find<T: StackError + 'static>(&self) -> Option<&T>
+    where
+        Self: Sized + 'static,
+    {
+        self.find_source::<T>()
+    }
+
+    /// Returns the first error in the chain, starting with `self`, whose
+    /// concrete type is `T`, which need not be a `StackError`.
+    ///
+    /// Like [`find`](StackError::find), but also reaches plain errors past
+    /// the last `StackError`, such as an `io::Error` at the root.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use suzunari_error::*;
+    ///
+    /// #[suzunari_error]
+    /// #[suzu(display("config unreadable"))]
+    /// struct ConfigError {
+    ///     source: std::io::Error,
+    /// }
+    ///
+    /// let err = std::fs::read("/nonexistent/config.toml")
+    ///     .context(ConfigSnafu)
+    ///     .unwrap_err();
+    /// let io = err.find_source::<std::io::Error>().unwrap();
+    /// assert_eq!(io.kind(), std::io::ErrorKind::NotFound);
+    /// ```
+    #[must_use]
+    fn find_source<T: Error + 'static>(&self) -> Option<&T>
+    where
+        Self: Sized + 'static,
+    {
+        let mut current: Option<&(dyn Error + 'static)> = Some(self);
+        while let Some(error) = current {
+            if let Some(found) = downcast_through_boxes::<T>(error) {
+                return Some(found);
+            }
+            // A `BoxedStackError` shares its source with the boxed error,
+            // so the unboxed `error` is the right place to continue from.
+            current = error.source();
+        }
+        None
+    }
+
+    /// Returns whether any error in the chain, starting with `self`, has the
+    /// concrete type `T`.
+    ///
+    /// Shorthand for [`find_source`](StackError::find_source)`::<T>().is_some()`,
+    /// convenient in `if` conditions and match guards.
+    ///
+    /// ```
+    /// use suzunari_error::*;
+    ///
+    /// #[suzunari_error]
+    /// #[suzu(display("config unreadable"))]
+    /// struct ConfigError {
+    ///     source: std::io::Error,
+    /// }
+    ///
+    /// let err = std::fs::read("/nonexistent/config.toml")
+    ///     .context(ConfigSnafu)
+    ///     .unwrap_err();
+    /// assert!(err.source_is::<std::io::Error>());
+    /// assert!(!err.source_is::<std::fmt::Error>());
+    /// ```
+    #[must_use]
+    fn source_is<T: Error + 'static>(&self) -> bool
+    where
+        Self: Sized + 'static,
+    {
+        self.find_source::<T>().is_some()
+    }
+
     /// Returns the number of errors in the `Error::source()` chain (excluding self).
     ///
-    /// Traverses the full `Error::source()` chain (not `stack_source()`),
-    /// counting both `StackError` and non-`StackError` causes.
+    /// An error without a source has depth 0, and each `source()` hop adds
+    /// one. The full `Error::source()` chain is traversed (not
+    /// `stack_source()`), so non-`StackError` causes such as `io::Error`
+    /// count as well.
+    ///
+    /// This is the number of `Caused by` lines in an untruncated
+    /// `StackReport`; the report additionally prints `self` on its first line.
+    ///
+    /// Does not terminate if the chain loops back on itself. `StackReport`
+    /// bounds its own traversal instead of relying on this method; use
+    /// `StackReport::frame_count` to count frames of a possibly looping chain.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use suzunari_error::*;
+    ///
+    /// #[suzunari_error]
+    /// #[suzu(display("disk full"))]
+    /// struct DiskError {}
+    ///
+    /// #[suzunari_error]
+    /// #[suzu(display("write failed"))]
+    /// struct WriteError {
+    ///     source: DiskError,
+    /// }
+    ///
+    /// #[suzunari_error]
+    /// #[suzu(display("save failed"))]
+    /// struct SaveError {
+    ///     source: WriteError,
+    /// }
+    ///
+    /// let disk = DiskSnafu.build();
+    /// assert_eq!(disk.depth(), 0);
     ///
-    /// Note: this count may differ from the number of lines in `StackReport`
-    /// output, which also shows the top-level error on the first line.
+    /// let save = Err::<(), _>(disk)
+    ///     .context(WriteSnafu)
+    ///     .context(SaveSnafu)
+    ///     .unwrap_err();
+    /// assert_eq!(save.depth(), 2);
+    /// assert_eq!(save.stack_source().unwrap().depth(), 1);
+    /// ```
     #[must_use]
     fn depth(&self) -> usize {
         // successors() can't be used here due to trait object lifetime constraints:
@@ -109,6 +450,320 @@ pub trait StackError: Error {
         }
         count
     }
+
+    /// Returns the number of `stack_source()` hops below `self`.
+    ///
+    /// Counts only the `StackError` part of the chain, the causes that
+    /// `StackReport` prints with a location. Plain `Error::source()` causes
+    /// below the last `StackError` are left out, so
+    /// `stack_depth() <= total_depth()`.
+    ///
+    /// Like [`depth`](StackError::depth), does not terminate if the chain
+    /// loops back on itself.
+    ///
+    /// ```
+    /// use suzunari_error::*;
+    ///
+    /// #[suzunari_error]
+    /// #[suzu(display("read failed"))]
+    /// struct ReadError {
+    ///     source: std::io::Error,
+    /// }
+    ///
+    /// #[suzunari_error]
+    /// #[suzu(display("load failed"))]
+    /// struct LoadError {
+    ///     source: ReadError,
+    /// }
+    ///
+    /// let io = std::io::Error::other("disk unplugged");
+    /// let err = Err::<(), _>(io)
+    ///     .context(ReadSnafu)
+    ///     .context(LoadSnafu)
+    ///     .unwrap_err();
+    /// assert_eq!(err.stack_depth(), 1); // ReadError
+    /// assert_eq!(err.total_depth(), 2); // ReadError, io::Error
+    /// ```
+    #[must_use]
+    fn stack_depth(&self) -> usize {
+        let mut count = 0;
+        let mut current = self.stack_source();
+        while let Some(e) = current {
+            count += 1;
+            current = e.stack_source();
+        }
+        count
+    }
+
+    /// Returns the number of errors in the whole `Error::source()` chain
+    /// below `self`: the `stack_source()` hops plus the plain `source()`
+    /// tail.
+    ///
+    /// Same as [`depth`](StackError::depth), named to pair with
+    /// [`stack_depth`](StackError::stack_depth). An untruncated
+    /// `StackReport` prints `1 + total_depth()` frames, minus transparent
+    /// ones.
+    ///
+    /// Like [`depth`](StackError::depth), does not terminate if the chain
+    /// loops back on itself; `StackReport::frame_count` is a bounded
+    /// alternative.
+    #[must_use]
+    fn total_depth(&self) -> usize {
+        self.depth()
+    }
+}
+
+/// Replaces the location an error reports, for errors built away from the
+/// site they describe, e.g., inside a callback or a retry helper.
+///
+/// `#[derive(StackError)]`, and so `#[suzunari_error]`, implements it when
+/// the location field has the type [`Location`]; for an enum, every variant
+/// must have one. Custom [`AsLocation`] fields and `skip_location` items are
+/// not relocatable.
+///
+/// Errors are otherwise not modified after they are built, so relocating
+/// takes the error by value and returns it. Only this frame moves: sources
+/// keep the locations they were created at.
+///
+/// ```
+/// use suzunari_error::*;
+///
+/// #[suzunari_error]
+/// #[suzu(display("job {id} failed"))]
+/// struct JobError {
+///     id: u32,
+/// }
+///
+/// fn run_job(id: u32, on_error: impl FnOnce(u32) -> JobError) -> JobError {
+///     on_error(id)
+/// }
+///
+/// let err = run_job(7, |id| JobSnafu { id }.build()).relocate_here();
+/// assert_eq!(err.location().line(), line!() - 1);
+/// ```
+pub trait RelocateStackError: StackError + Sized {
+    /// Returns `self` reporting `location` instead of the captured one.
+    #[must_use]
+    fn relocate(self, location: Location) -> Self;
+
+    /// Returns `self` located at the caller.
+    #[must_use]
+    #[track_caller]
+    fn relocate_here(self) -> Self {
+        self.relocate(core::panic::Location::caller())
+    }
+}
+
+/// Conversion from a location field to the [`Location`] reported by
+/// [`StackError::location`].
+///
+/// `derive(StackError)` reads the location field through this trait, so a
+/// field marked `#[suzu(location)]` (or `#[stack(location)]`) may use any
+/// type implementing it. With `#[suzu(location)]` the type must also
+/// implement [`snafu::GenerateImplicitData`] so it can be captured
+/// implicitly. Fields of the built-in [`Location`] type need nothing extra.
+///
+/// ```
+/// use suzunari_error::*;
+/// use suzunari_error::snafu::GenerateImplicitData;
+///
+/// #[derive(Debug, Clone, Copy)]
+/// struct SiteLocation(Location);
+///
+/// impl GenerateImplicitData for SiteLocation {
+///     #[track_caller]
+///     fn generate() -> Self {
+///         SiteLocation(core::panic::Location::caller())
+///     }
+/// }
+///
+/// impl AsLocation for SiteLocation {
+///     fn as_location(&self) -> Location {
+///         self.0
+///     }
+/// }
+///
+/// #[suzunari_error]
+/// #[suzu(display("request failed"))]
+/// struct RequestError {
+///     #[suzu(location)]
+///     site: SiteLocation,
+/// }
+///
+/// let err = RequestSnafu.build();
+/// assert_eq!(err.location(), err.site.0);
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot be used as a location field",
+    note = "location fields must be `suzunari_error::Location` or implement `suzunari_error::AsLocation`"
+)]
+pub trait AsLocation {
+    /// Returns the location this value records.
+    fn as_location(&self) -> Location;
+}
+
+impl AsLocation for Location {
+    fn as_location(&self) -> Location {
+        self
+    }
+}
+
+/// How severe an error is, as reported by [`StackError::severity`].
+///
+/// Levels are ordered from least to most severe, so they can be compared
+/// against a threshold (e.g., `err.severity() >= Severity::Error`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[non_exhaustive]
+pub enum Severity {
+    /// Informational; the operation failed in an expected way.
+    Info,
+    /// Degraded but recoverable.
+    Warning,
+    /// An ordinary failure. The default.
+    #[default]
+    Error,
+    /// Requires immediate attention.
+    Critical,
+}
+
+/// Iterator over the locations of a `StackError` chain.
+///
+/// Created by [`StackError::locations`].
+#[derive(Clone)]
+pub struct Locations<'a> {
+    first: Option<Location>,
+    next: Option<&'a dyn StackError>,
+}
+
+impl Iterator for Locations<'_> {
+    type Item = Location;
+
+    fn next(&mut self) -> Option<Location> {
+        if let Some(first) = self.first.take() {
+            return Some(first);
+        }
+        let current = self.next?;
+        self.next = current.stack_source();
+        Some(current.location())
+    }
+}
+
+impl FusedIterator for Locations<'_> {}
+
+impl Debug for Locations<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+/// Downcasts `error` to `T`, looking inside `BoxedStackError`s when needed.
+fn downcast_through_boxes<'a, T: Error + 'static>(
+    error: &'a (dyn Error + 'static),
+) -> Option<&'a T> {
+    if let Some(found) = error.downcast_ref::<T>() {
+        return Some(found);
+    }
+    #[cfg(feature = "alloc")]
+    if let Some(boxed) = error.downcast_ref::<crate::BoxedStackError>() {
+        return downcast_through_boxes(boxed.inner_error());
+    }
+    #[cfg(feature = "alloc")]
+    if let Some(shared) = error.downcast_ref::<crate::SharedStackError>() {
+        return downcast_through_boxes(shared.inner_error());
+    }
+    None
+}
+
+/// Delegates all methods to the referenced value.
+///
+/// Covers both sized `&T` and trait objects such as `&'static dyn StackError`
+/// (e.g., static error tables in `no_std` environments), so references can be
+/// passed to [`StackReport`](crate::StackReport) directly. `core` provides the
+/// matching `impl Error for &T`.
+impl<T: ?Sized + StackError> StackError for &T {
+    fn location(&self) -> Location {
+        (**self).location()
+    }
+    fn type_name(&self) -> &'static str {
+        (**self).type_name()
+    }
+    fn module_path(&self) -> Option<&'static str> {
+        (**self).module_path()
+    }
+    fn severity(&self) -> Severity {
+        (**self).severity()
+    }
+    fn is_transparent(&self) -> bool {
+        (**self).is_transparent()
+    }
+    fn stack_source(&self) -> Option<&dyn StackError> {
+        (**self).stack_source()
+    }
+    fn metadata(&self, visitor: &mut dyn FnMut(&'static str, &dyn Display)) {
+        (**self).metadata(visitor)
+    }
+    #[cfg(feature = "backtrace")]
+    fn backtrace(&self) -> Option<&std::backtrace::Backtrace> {
+        (**self).backtrace()
+    }
+}
+
+/// Returns early with a `StackError` built in place if `cond` is false.
+///
+/// The error is written as a struct literal without its location field,
+/// which is filled with the caller's location:
+///
+/// ```
+/// use suzunari_error::*;
+///
+/// #[suzunari_error]
+/// enum ConfigError {
+///     #[suzu(display("port {port} is out of range"))]
+///     InvalidPort { port: u32 },
+///     #[suzu(display("config is empty"))]
+///     Empty,
+/// }
+///
+/// fn check(port: u32) -> Result<(), ConfigError> {
+///     ensure_stack!(port != 0, ConfigError::Empty);
+///     ensure_stack!(port < 65536, ConfigError::InvalidPort { port });
+///     Ok(())
+/// }
+///
+/// assert!(check(80).is_ok());
+/// let err = check(70000).unwrap_err();
+/// assert_eq!(err.to_string(), "port 70000 is out of range");
+/// ```
+///
+/// # Differences from `snafu::ensure!`
+///
+/// - Takes the error type or variant path itself instead of a context
+///   selector, so no `...Snafu` selector has to be imported.
+/// - Fields are given as in a struct literal (`field` or `field: value`),
+///   without snafu's `Into` conversion of selector fields.
+/// - The location field must be named `location`, which is the name
+///   `#[suzunari_error]` injects. Use `ensure!` with a context selector for
+///   custom location field names.
+/// - The error is converted with `Into` at the return, like `ensure!`.
+#[macro_export]
+macro_rules! ensure_stack {
+    // Paths are matched as `ident::ident` segments because a `$:path`
+    // fragment cannot be used as the name of a struct literal.
+    ($cond:expr, $($error:ident)::+ { $($field:ident $(: $value:expr)?),* $(,)? } $(,)?) => {
+        if !$cond {
+            return ::core::result::Result::Err(::core::convert::Into::into($($error)::+ {
+                $($field $(: $value)?,)*
+                location: ::core::panic::Location::caller(),
+            }));
+        }
+    };
+    ($cond:expr, $($error:ident)::+ $(,)?) => {
+        if !$cond {
+            return ::core::result::Result::Err(::core::convert::Into::into($($error)::+ {
+                location: ::core::panic::Location::caller(),
+            }));
+        }
+    };
 }
 
 #[cfg(feature = "alloc")]
@@ -129,9 +784,25 @@ mod alloc_impls {
         fn type_name(&self) -> &'static str {
             self.as_ref().type_name()
         }
+        fn module_path(&self) -> Option<&'static str> {
+            self.as_ref().module_path()
+        }
+        fn severity(&self) -> Severity {
+            self.as_ref().severity()
+        }
+        fn is_transparent(&self) -> bool {
+            self.as_ref().is_transparent()
+        }
         fn stack_source(&self) -> Option<&dyn StackError> {
             self.as_ref().stack_source()
         }
+        fn metadata(&self, visitor: &mut dyn FnMut(&'static str, &dyn Display)) {
+            self.as_ref().metadata(visitor)
+        }
+        #[cfg(feature = "backtrace")]
+        fn backtrace(&self) -> Option<&std::backtrace::Backtrace> {
+            self.as_ref().backtrace()
+        }
     }
     /// Delegates all methods to the inner `T` via `Arc::as_ref`.
     impl<T: ?Sized + StackError> StackError for Arc<T> {
@@ -141,9 +812,25 @@ mod alloc_impls {
         fn type_name(&self) -> &'static str {
             self.as_ref().type_name()
         }
+        fn module_path(&self) -> Option<&'static str> {
+            self.as_ref().module_path()
+        }
+        fn severity(&self) -> Severity {
+            self.as_ref().severity()
+        }
+        fn is_transparent(&self) -> bool {
+            self.as_ref().is_transparent()
+        }
         fn stack_source(&self) -> Option<&dyn StackError> {
             self.as_ref().stack_source()
         }
+        fn metadata(&self, visitor: &mut dyn FnMut(&'static str, &dyn Display)) {
+            self.as_ref().metadata(visitor)
+        }
+        #[cfg(feature = "backtrace")]
+        fn backtrace(&self) -> Option<&std::backtrace::Backtrace> {
+            self.as_ref().backtrace()
+        }
     }
 
     /// Routes `Error::source` through the trait object.
@@ -161,9 +848,25 @@ mod alloc_impls {
         fn type_name(&self) -> &'static str {
             self.as_ref().type_name()
         }
+        fn module_path(&self) -> Option<&'static str> {
+            self.as_ref().module_path()
+        }
+        fn severity(&self) -> Severity {
+            self.as_ref().severity()
+        }
+        fn is_transparent(&self) -> bool {
+            self.as_ref().is_transparent()
+        }
         fn stack_source(&self) -> Option<&dyn StackError> {
             self.as_ref().stack_source()
         }
+        fn metadata(&self, visitor: &mut dyn FnMut(&'static str, &dyn Display)) {
+            self.as_ref().metadata(visitor)
+        }
+        #[cfg(feature = "backtrace")]
+        fn backtrace(&self) -> Option<&std::backtrace::Backtrace> {
+            self.as_ref().backtrace()
+        }
     }
 
     /// Routes `Error::source` through the thread-safe trait object.
@@ -181,9 +884,25 @@ mod alloc_impls {
         fn type_name(&self) -> &'static str {
             self.as_ref().type_name()
         }
+        fn module_path(&self) -> Option<&'static str> {
+            self.as_ref().module_path()
+        }
+        fn severity(&self) -> Severity {
+            self.as_ref().severity()
+        }
+        fn is_transparent(&self) -> bool {
+            self.as_ref().is_transparent()
+        }
         fn stack_source(&self) -> Option<&dyn StackError> {
             self.as_ref().stack_source()
         }
+        fn metadata(&self, visitor: &mut dyn FnMut(&'static str, &dyn Display)) {
+            self.as_ref().metadata(visitor)
+        }
+        #[cfg(feature = "backtrace")]
+        fn backtrace(&self) -> Option<&std::backtrace::Backtrace> {
+            self.as_ref().backtrace()
+        }
     }
 }
 
@@ -341,6 +1060,70 @@ mod tests {
         assert_eq!(wrapper.depth(), 1);
     }
 
+    #[test]
+    fn test_own_message_matches_first_report_line() {
+        fn gen_root() -> Result<(), Box<dyn StackError + Send + Sync + 'static>> {
+            Err(Box::new(SimpleSnafu { message: "root" }.build()))
+        }
+        let wrapper = gen_root()
+            .context(WrapperSnafu { message: "top" })
+            .unwrap_err();
+        assert_eq!(wrapper.own_message(), "Wrapper error: top");
+
+        let report = format!("{}", StackReport::from_error(&wrapper));
+        let first_line = report.lines().next().unwrap();
+        let expected = format!("Error: WrapperError: {}, at ", wrapper.own_message());
+        assert!(first_line.starts_with(&expected), "{first_line}");
+        assert!(!first_line.contains("root"));
+    }
+
+    #[test]
+    fn test_reference_is_stack_error() {
+        fn describe<E: StackError>(error: E) -> (&'static str, u32, bool) {
+            (
+                error.type_name(),
+                error.location().line(),
+                error.stack_source().is_some(),
+            )
+        }
+        fn gen_root() -> Result<(), Box<dyn StackError + Send + Sync + 'static>> {
+            Err(Box::new(SimpleSnafu { message: "root" }.build()))
+        }
+        let wrapper = gen_root()
+            .context(WrapperSnafu { message: "wrapper" })
+            .unwrap_err();
+
+        let (type_name, line, has_source) = describe(&wrapper);
+        assert_eq!(type_name, "WrapperError");
+        assert_eq!(line, wrapper.location().line());
+        assert!(has_source);
+        // Still owned by the caller
+        assert_eq!(wrapper.depth(), 1);
+    }
+
+    #[test]
+    fn test_depth_zero() {
+        let error = SimpleSnafu { message: "leaf" }.build();
+        assert_eq!(error.depth(), 0);
+        // Wrappers delegate rather than adding a level
+        assert_eq!(Box::new(error).depth(), 0);
+    }
+
+    #[test]
+    fn test_depth_two() {
+        fn gen_wrapper() -> Result<(), Box<dyn StackError + Send + Sync + 'static>> {
+            let root: Result<(), Box<dyn StackError + Send + Sync + 'static>> =
+                Err(Box::new(SimpleSnafu { message: "root" }.build()));
+            let wrapper = root.context(WrapperSnafu { message: "inner" }).unwrap_err();
+            Err(Box::new(wrapper))
+        }
+        let outer = gen_wrapper()
+            .context(WrapperSnafu { message: "outer" })
+            .unwrap_err();
+        assert_eq!(outer.depth(), 2);
+        assert_eq!(outer.stack_source().unwrap().depth(), 1);
+    }
+
     #[test]
     fn test_box_concrete_stack_error() {
         // Box<T: Sized + StackError> blanket impl
@@ -1,10 +1,21 @@
-use crate::StackError;
+use crate::{Location, StackError};
+use core::error::Error;
 use core::fmt::{Debug, Display, Formatter};
+use core::iter::FusedIterator;
 
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, string::String};
+
+#[cfg(feature = "std")]
+use alloc::{borrow::Cow, string::ToString};
 #[cfg(feature = "std")]
 use std::io::{Write, stderr};
 #[cfg(feature = "std")]
+use std::path::{Path, PathBuf};
+#[cfg(feature = "std")]
 use std::process::{ExitCode, Termination};
+#[cfg(feature = "std")]
+use std::time::SystemTime;
 
 /// Formats a [`StackError`] chain as a stack-trace-like report with type names and locations.
 ///
@@ -23,8 +34,25 @@ use std::process::{ExitCode, Termination};
 /// ```
 ///
 /// The first line shows the top-level error with type name and location.
+/// Causes are numbered from `1` (the direct source) in recent-first order,
+/// each on its own line prefixed with two spaces and `{index}| `.
 /// StackError sources (with location) are listed first with numbering,
 /// then plain `Error::source()` chain entries (without location) follow.
+/// Frames with [metadata](StackError::metadata) append it after the location
+/// as ` (key=value, ...)`. [Transparent](StackError::is_transparent) causes
+/// are skipped, and numbering continues with the next printed cause.
+/// The `Error` and `Caused by (recent first)` labels can be replaced with
+/// [`header_label`](Self::header_label) and [`causes_label`](Self::causes_label),
+/// and the `{index}| ` numbering with [`index_style`](Self::index_style).
+///
+/// The alternate flag (`{:#}`) renders the same frames as an indented tree,
+/// each cause nested under the one it explains:
+///
+/// ```text
+/// Error: AppError::IoFailed: io failed, at src/main.rs:42:5
+/// ╰─▶ InfraError::Read: read failed, at src/infra.rs:10:9
+///     ╰─▶ No such file or directory (os error 2)
+/// ```
 ///
 /// With the `std` feature, implements [`Termination`] for use as the
 /// return type of `main()`. The [`#[suzunari_error::report]`](crate::report) macro
@@ -69,17 +97,1005 @@ use std::process::{ExitCode, Termination};
 ///   the convention for `Display` implementations and avoids double newlines
 ///   with `eprintln!("{report}")`. The `Termination` impl adds a trailing
 ///   newline when writing to stderr.
-pub struct StackReport<E>(Result<(), E>);
+pub struct StackReport<E> {
+    result: Result<(), E>,
+    options: ReportOptions,
+}
+
+/// Rendering options configured through the `StackReport` builder methods.
+#[derive(Default)]
+struct ReportOptions {
+    /// Maximum number of `Caused by` lines to print. `None` means unlimited.
+    max_depth: Option<usize>,
+    /// Leading path trimmed from plain locations. See [`StackReport::strip_prefix`].
+    strip_prefix: Option<&'static str>,
+    /// Replaces `Error` on the first line. See [`StackReport::header_label`].
+    header_label: Option<&'static str>,
+    /// Replaces `Caused by (recent first)`. See [`StackReport::causes_label`].
+    causes_label: Option<&'static str>,
+    /// Numbering of cause lines. See [`StackReport::index_style`].
+    index_style: IndexStyle,
+    /// Appends the root cause's `Debug`. See [`StackReport::with_root_debug`].
+    root_debug: bool,
+    /// Omits `, at <location>`. See [`StackReport::hide_locations`].
+    hide_locations: bool,
+    /// Omits the `TypeName: ` prefix. See [`StackReport::hide_type_names`].
+    hide_type_names: bool,
+    /// Prepends a timestamp and thread name on exit. See [`StackReport::with_exit_context`].
+    #[cfg(feature = "std")]
+    exit_context: bool,
+    style: StackReportStyle,
+    /// Renders every location in place of `style`. See [`StackReport::map_locations`].
+    #[cfg(feature = "alloc")]
+    location_mapper: Option<LocationMapper>,
+    /// Drops plain causes repeating the previous message. See [`StackReport::dedup_adjacent`].
+    #[cfg(feature = "alloc")]
+    dedup_adjacent: bool,
+}
+
+#[cfg(feature = "alloc")]
+type LocationMapper = Box<dyn Fn(&Location) -> String + Send + Sync>;
+
+/// How cause lines are numbered, applied via [`StackReport::index_style`].
+///
+/// Each cause line starts with two spaces followed by the index in this
+/// style. The tree layout (`{:#}`) has no indices and ignores it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum IndexStyle {
+    /// `  1| cause` (the default).
+    #[default]
+    Pipe,
+    /// `  [1] cause`.
+    Bracket,
+    /// `  1. cause`.
+    Dot,
+}
+
+/// Presentation settings for [`StackReport`], applied via [`StackReport::style`].
+///
+/// Start from [`StackReportStyle::default()`] (plain output) and set the
+/// fields you need:
+///
+/// ```
+/// use suzunari_error::{LocationUriScheme, StackReportStyle};
+///
+/// let mut style = StackReportStyle::default();
+/// style.location_uri = Some(LocationUriScheme::VsCode {
+///     base_dir: std::env::current_dir().unwrap(),
+/// });
+/// ```
+#[derive(Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct StackReportStyle {
+    /// Renders locations as clickable links instead of plain `file:line:column`.
+    /// `None` (the default) keeps the plain form.
+    #[cfg(feature = "std")]
+    pub location_uri: Option<LocationUriScheme>,
+    /// Prints only the file name of each location (`main.rs:42:5` instead of
+    /// `src/bin/main.rs:42:5`), so shipped binaries do not reveal build paths.
+    /// Takes precedence over `location_uri`, since a link needs the full path.
+    /// Defaults to `false`.
+    pub hide_paths: bool,
+    /// Appends ` in <module path>` after each location, using
+    /// [`StackError::module_path`]. Frames without a module path are unchanged.
+    /// Defaults to `false`.
+    pub show_module_path: bool,
+}
+
+/// How [`StackReportStyle::location_uri`] turns a location into a link.
+///
+/// `Location::file()` is usually relative to the crate being compiled, so
+/// each scheme resolves it against `base_dir` (typically the workspace root).
+/// Absolute file paths are used as-is.
+#[cfg(feature = "std")]
+#[derive(Clone, Debug)]
+pub enum LocationUriScheme {
+    /// Wraps the plain `file:line:column` text in an
+    /// [OSC 8](https://gist.github.com/egmontkob/eb114294efbcd5adb1944c9f3cb5feda)
+    /// terminal hyperlink pointing to `file://<absolute path>`.
+    Osc8 {
+        /// Directory that relative location paths are resolved against.
+        base_dir: PathBuf,
+    },
+    /// Renders `vscode://file/<absolute path>:<line>:<column>`.
+    VsCode {
+        /// Directory that relative location paths are resolved against.
+        base_dir: PathBuf,
+    },
+    /// Renders `template` with `{path}`, `{line}`, and `{column}` replaced by
+    /// the absolute path, line, and column, for editors without a built-in
+    /// scheme. Other text, including unknown `{...}` placeholders, is kept
+    /// as-is.
+    ///
+    /// ```
+    /// use suzunari_error::LocationUriScheme;
+    ///
+    /// let scheme = LocationUriScheme::Template {
+    ///     base_dir: "/work".into(),
+    ///     template: "idea://open?file={path}&line={line}".into(),
+    /// };
+    /// let location = core::panic::Location::caller();
+    /// let url = scheme.url(location);
+    /// assert!(url.starts_with("idea://open?file=/"));
+    /// assert!(url.ends_with(&format!("&line={}", location.line())));
+    /// ```
+    Template {
+        /// Directory that relative location paths are resolved against.
+        base_dir: PathBuf,
+        /// The URL with `{path}`, `{line}`, and `{column}` placeholders.
+        template: Cow<'static, str>,
+    },
+}
+
+#[cfg(feature = "std")]
+impl LocationUriScheme {
+    /// Returns the link target for `location`, e.g., for a terminal
+    /// hyperlink outside a report.
+    ///
+    /// For [`Osc8`](Self::Osc8) this is the `file://` URL, which cannot carry
+    /// a line or column; the other schemes include both.
+    ///
+    /// ```
+    /// use suzunari_error::LocationUriScheme;
+    ///
+    /// let scheme = LocationUriScheme::VsCode { base_dir: "/work".into() };
+    /// let location = core::panic::Location::caller();
+    /// let url = scheme.url(location);
+    /// assert!(url.starts_with("vscode://file/"));
+    /// assert!(url.ends_with(&location.to_string()));
+    /// ```
+    #[must_use]
+    pub fn url(&self, location: Location) -> String {
+        LocationUrl {
+            location,
+            scheme: self,
+        }
+        .to_string()
+    }
+
+    fn base_dir(&self) -> &Path {
+        match self {
+            Self::Osc8 { base_dir }
+            | Self::VsCode { base_dir }
+            | Self::Template { base_dir, .. } => base_dir,
+        }
+    }
+}
+
+/// Formats the link target of a location for [`LocationUriScheme`].
+#[cfg(feature = "std")]
+struct LocationUrl<'a> {
+    location: Location,
+    scheme: &'a LocationUriScheme,
+}
+
+#[cfg(feature = "std")]
+impl Display for LocationUrl<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let location = self.location;
+        // `join` keeps `location.file()` unchanged when it is already absolute.
+        let path = self.scheme.base_dir().join(location.file());
+        // URIs need a leading `/` before the path; Unix absolute paths already have one.
+        let slash = if path.starts_with("/") { "" } else { "/" };
+        let path = path.display();
+        match self.scheme {
+            LocationUriScheme::Osc8 { .. } => write!(f, "file://{slash}{path}"),
+            LocationUriScheme::VsCode { .. } => write!(
+                f,
+                "vscode://file{slash}{path}:{}:{}",
+                location.line(),
+                location.column()
+            ),
+            LocationUriScheme::Template { template, .. } => {
+                let mut rest: &str = template;
+                while let Some(start) = rest.find('{') {
+                    f.write_str(&rest[..start])?;
+                    rest = &rest[start..];
+                    if let Some(after) = rest.strip_prefix("{path}") {
+                        write!(f, "{path}")?;
+                        rest = after;
+                    } else if let Some(after) = rest.strip_prefix("{line}") {
+                        write!(f, "{}", location.line())?;
+                        rest = after;
+                    } else if let Some(after) = rest.strip_prefix("{column}") {
+                        write!(f, "{}", location.column())?;
+                        rest = after;
+                    } else {
+                        f.write_str("{")?;
+                        rest = &rest[1..];
+                    }
+                }
+                f.write_str(rest)
+            }
+        }
+    }
+}
+
+/// Lays out the frames of a report, for formats the built-in one cannot
+/// produce, such as CI annotations or logfmt. Applied with
+/// [`StackReport::with_style`].
+///
+/// The report walks the chain and calls [`frame`](Self::frame) for each
+/// printed frame, top-level error first as index `0`, then the causes from
+/// `1`. Transparent frames are skipped, and [`max_depth`](StackReport::max_depth)
+/// and the cycle guard end the walk with a call to
+/// [`elision`](Self::elision). A style writes its own separators; the
+/// built-in layout starts every line after the first with `\n`.
+///
+/// Unlike [`StackReportStyle`], which tunes the built-in layout, a
+/// `ReportStyle` replaces it. [`DefaultReportStyle`] reproduces it.
+///
+/// ```
+/// use core::fmt::Formatter;
+/// use suzunari_error::*;
+///
+/// #[suzunari_error]
+/// #[suzu(display("disk full"))]
+/// struct DiskError {}
+///
+/// #[suzunari_error]
+/// #[suzu(display("save failed"))]
+/// struct SaveError {
+///     source: DiskError,
+/// }
+///
+/// /// Prints `SaveError <- DiskError`.
+/// struct Arrows;
+///
+/// impl ReportStyle for Arrows {
+///     fn frame(&self, index: usize, f: &mut Formatter<'_>, frame: Link<'_>) -> core::fmt::Result {
+///         if index > 0 {
+///             f.write_str(" <- ")?;
+///         }
+///         match frame {
+///             Link::StackFrame { type_name, .. } => f.write_str(type_name),
+///             _ => f.write_str("?"),
+///         }
+///     }
+/// }
+///
+/// let err = Err::<(), _>(DiskSnafu.build()).context(SaveSnafu).unwrap_err();
+/// let report = StackReport::from_error(err).with_style(Arrows);
+/// assert_eq!(report.to_string(), "SaveError <- DiskError");
+/// ```
+pub trait ReportStyle {
+    /// Writes the `index`-th printed frame; `0` is the top-level error.
+    fn frame(&self, index: usize, f: &mut Formatter<'_>, frame: Link<'_>) -> core::fmt::Result;
+
+    /// Writes the marker that replaces the `index`-th printed cause and
+    /// everything after it: `Some(n)` when [`max_depth`](StackReport::max_depth)
+    /// elides `n` causes, `None` when the chain possibly loops. Writes
+    /// nothing by default.
+    fn elision(
+        &self,
+        index: usize,
+        f: &mut Formatter<'_>,
+        remaining: Option<usize>,
+    ) -> core::fmt::Result {
+        let _ = (index, f, remaining);
+        Ok(())
+    }
+}
+
+/// The [`ReportStyle`] of a plain [`StackReport`] with default settings,
+/// including the tree layout under `{:#}`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DefaultReportStyle;
+
+impl ReportStyle for DefaultReportStyle {
+    fn frame(&self, index: usize, f: &mut Formatter<'_>, frame: Link<'_>) -> core::fmt::Result {
+        let options = ReportOptions::default();
+        BuiltinStyle { options: &options }.frame(index, f, frame)
+    }
+
+    fn elision(
+        &self,
+        index: usize,
+        f: &mut Formatter<'_>,
+        remaining: Option<usize>,
+    ) -> core::fmt::Result {
+        let options = ReportOptions::default();
+        BuiltinStyle { options: &options }.elision(index, f, remaining)
+    }
+}
+
+impl<E: StackError> StackReport<E> {
+    /// Creates a report for `error`.
+    ///
+    /// Equivalent to `StackReport::from(error)`, but reads better as the
+    /// start of a builder chain.
+    #[must_use]
+    pub fn from_error(error: E) -> Self {
+        Self::from(error)
+    }
+
+    /// Creates a report for `error` converted into `E`, e.g., a concrete
+    /// error into a `StackReport<BoxedStackError>` through the generated
+    /// `From<T> for BoxedStackError`.
+    ///
+    /// A separate method because a generic [`from_error`](Self::from_error)
+    /// would leave `E` uninferred at call sites that don't name it.
+    ///
+    /// ```
+    /// use suzunari_error::*;
+    ///
+    /// #[suzunari_error]
+    /// #[suzu(display("cache miss"))]
+    /// struct CacheError {}
+    ///
+    /// let report: StackReport<BoxedStackError> = StackReport::from_into(CacheSnafu.build());
+    /// assert!(report.to_string().starts_with("Error: CacheError: cache miss, at "));
+    /// ```
+    #[must_use]
+    pub fn from_into(error: impl Into<E>) -> Self {
+        Self::from(error.into())
+    }
+
+    /// Creates a report for `result`, which renders nothing when `Ok`.
+    ///
+    /// Equivalent to `StackReport::from(result)`. See also
+    /// [`ResultReportExt::into_report`] for the method form.
+    #[must_use]
+    pub fn from_result(result: Result<(), E>) -> Self {
+        Self::from(result)
+    }
+
+    /// Limits the number of `Caused by` lines to `max_depth`.
+    ///
+    /// When the chain is longer, the remaining causes are replaced by a
+    /// single `... (N more)` line, where `N` counts the causes that would
+    /// have been printed. [Transparent](StackError::is_transparent) frames
+    /// are never printed or counted. With `max_depth(0)`, only the top-level
+    /// error line is printed.
+    ///
+    /// Independently of this setting, a report stops after 128 causes with a
+    /// `... (possible cycle)` line, so a chain that loops back on itself
+    /// cannot hang the formatter.
+    ///
+    /// ```text
+    /// Error: AppError: outer, at src/main.rs:42:5
+    /// Caused by (recent first):
+    ///   1| InfraError: middle, at src/infra.rs:10:9
+    ///   ... (2 more)
+    /// ```
+    #[must_use]
+    pub fn max_depth(mut self, max_depth: usize) -> Self {
+        self.options.max_depth = Some(max_depth);
+        self
+    }
+
+    /// Trims `prefix` from the start of each printed location's file path.
+    ///
+    /// Makes reports stable across checkouts, e.g. for snapshots in CI logs.
+    /// The prefix must match whole leading path components (`src` trims
+    /// `src/db.rs` but not `srcgen/db.rs`); a trailing separator is
+    /// optional. Files outside `prefix` are printed unchanged. Only the
+    /// rendered text is affected, not the errors' [`Location`]s. Applies to
+    /// the plain `file:line:column` form; [`map_locations`](Self::map_locations),
+    /// `hide_paths`, and `location_uri` take precedence.
+    ///
+    /// ```
+    /// use suzunari_error::*;
+    ///
+    /// #[suzunari_error]
+    /// #[suzu(display("failed"))]
+    /// struct AppError {}
+    ///
+    /// let err = AppSnafu.build();
+    /// let file = err.location().file();
+    /// let file_name = std::path::Path::new(file).file_name().unwrap().to_str().unwrap();
+    /// let dir: &'static str = &file[..file.len() - file_name.len()];
+    ///
+    /// let report = StackReport::from_error(err).strip_prefix(dir);
+    /// assert!(format!("{report}").contains(&format!(", at {file_name}:")));
+    /// ```
+    #[must_use]
+    pub fn strip_prefix(mut self, prefix: &'static str) -> Self {
+        self.options.strip_prefix = Some(prefix);
+        self
+    }
+
+    /// Replaces the `Error` label that starts the first line.
+    ///
+    /// The `: ` after the label is kept. Useful for localized output or for
+    /// reports embedded in larger output, e.g. `header_label("Warning")`.
+    ///
+    /// ```
+    /// use suzunari_error::*;
+    ///
+    /// #[suzunari_error]
+    /// #[suzu(display("failed"))]
+    /// struct AppError {}
+    ///
+    /// let report = StackReport::from_error(AppSnafu.build()).header_label("Fehler");
+    /// assert!(format!("{report}").starts_with("Fehler: AppError: failed, at "));
+    /// ```
+    #[must_use]
+    pub fn header_label(mut self, label: &'static str) -> Self {
+        self.options.header_label = Some(label);
+        self
+    }
+
+    /// Replaces the `Caused by (recent first)` label that introduces the
+    /// causes. The trailing `:` is kept.
+    ///
+    /// Has no effect on the tree layout (`{:#}`), which prints no such line.
+    #[must_use]
+    pub fn causes_label(mut self, label: &'static str) -> Self {
+        self.options.causes_label = Some(label);
+        self
+    }
+
+    /// Sets how cause lines are numbered. Defaults to [`IndexStyle::Pipe`].
+    ///
+    /// ```
+    /// use suzunari_error::*;
+    ///
+    /// #[suzunari_error]
+    /// #[suzu(display("disk full"))]
+    /// struct DiskError {}
+    ///
+    /// #[suzunari_error]
+    /// #[suzu(display("save failed"))]
+    /// struct SaveError {
+    ///     source: DiskError,
+    /// }
+    ///
+    /// let err = Err::<(), _>(DiskSnafu.build()).context(SaveSnafu).unwrap_err();
+    /// let report = StackReport::from_error(err).index_style(IndexStyle::Bracket);
+    /// assert!(format!("{report}").contains("\n  [1] DiskError: disk full, at "));
+    /// ```
+    #[must_use]
+    pub fn index_style(mut self, style: IndexStyle) -> Self {
+        self.options.index_style = style;
+        self
+    }
+
+    /// Appends a final `Root cause (debug): {:?}` line with the `Debug`
+    /// output of the deepest error in the chain, for field values that its
+    /// `Display` leaves out.
+    ///
+    /// ```
+    /// use suzunari_error::*;
+    ///
+    /// #[suzunari_error]
+    /// #[suzu(display("request rejected"))]
+    /// struct RejectedError {
+    ///     status: u16,
+    /// }
+    ///
+    /// let report = StackReport::from_error(RejectedSnafu { status: 429u16 }.build())
+    ///     .with_root_debug()
+    ///     .to_string();
+    /// let last = report.lines().last().unwrap();
+    /// assert!(last.starts_with("Root cause (debug): RejectedError { status: 429, location: "));
+    /// ```
+    #[must_use]
+    pub fn with_root_debug(mut self) -> Self {
+        self.options.root_debug = true;
+        self
+    }
+
+    /// Prepends a `[<timestamp> thread '<name>']` line to the output of
+    /// the [`Termination`] impl and [`StackReport::write_to`], telling when
+    /// and on which thread a binary failed. Kept by
+    /// [`with_style`](Self::with_style).
+    ///
+    /// The timestamp is ISO 8601 in UTC with milliseconds, e.g.,
+    /// `2024-05-01T09:30:12.345Z`. `Display` is unaffected, so the default
+    /// output stays the same from run to run.
+    ///
+    /// ```
+    /// use suzunari_error::*;
+    ///
+    /// #[suzunari_error]
+    /// #[suzu(display("sync failed"))]
+    /// struct SyncError {}
+    ///
+    /// let report = StackReport::from_error(SyncSnafu.build()).with_exit_context();
+    /// let mut out = Vec::new();
+    /// report.write_to(&mut out).unwrap();
+    /// let out = String::from_utf8(out).unwrap();
+    /// assert!(out.lines().next().unwrap().ends_with("Z thread 'main']"));
+    /// assert!(out.contains("\nError: SyncError: sync failed, at "));
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn with_exit_context(mut self) -> Self {
+        self.options.exit_context = true;
+        self
+    }
+
+    /// Writes what the [`Termination`] impl prints to stderr: the report
+    /// followed by a newline, or nothing for `Ok`.
+    ///
+    /// For sending the exit output somewhere else, e.g., a log file.
+    #[cfg(feature = "std")]
+    pub fn write_to<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        let Err(error) = &self.result else {
+            return Ok(());
+        };
+        if self.options.exit_context {
+            write_exit_context(writer)?;
+        }
+        let formatter = StackReportFormatter {
+            error,
+            options: &self.options,
+        };
+        // Display omits the trailing newline; a terminal wants one.
+        writeln!(writer, "{formatter}")
+    }
+
+    /// Leaves out a plain `source()` cause whose message equals the
+    /// previous printed line's, e.g., a wrapper error that repeats its
+    /// inner error's `Display`.
+    ///
+    /// Only plain causes are dropped; `StackError` frames carry a location
+    /// and are always printed. Messages are compared as rendered strings,
+    /// so each frame is formatted twice. Also applies under
+    /// [`StackReport::with_style`].
+    ///
+    /// ```
+    /// use suzunari_error::*;
+    ///
+    /// #[derive(Debug)]
+    /// struct ClientError(std::io::Error);
+    ///
+    /// // Repeats its source's message.
+    /// impl std::fmt::Display for ClientError {
+    ///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    ///         self.0.fmt(f)
+    ///     }
+    /// }
+    ///
+    /// impl std::error::Error for ClientError {
+    ///     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    ///         Some(&self.0)
+    ///     }
+    /// }
+    ///
+    /// #[suzunari_error]
+    /// #[suzu(display("fetch failed"))]
+    /// struct FetchError {
+    ///     source: ClientError,
+    /// }
+    ///
+    /// let client = ClientError(std::io::Error::other("connection reset"));
+    /// let err = Err::<(), _>(client).context(FetchSnafu).unwrap_err();
+    ///
+    /// let report = StackReport::from_error(err).dedup_adjacent();
+    /// assert!(report.to_string().ends_with("Caused by (recent first):\n  1| connection reset"));
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn dedup_adjacent(mut self) -> Self {
+        self.options.dedup_adjacent = true;
+        self
+    }
+
+    /// Omits the `, at <location>` segment from every frame, for reports
+    /// shown to end users rather than developers.
+    ///
+    /// Type names, module paths, and metadata are still printed.
+    ///
+    /// ```
+    /// use suzunari_error::*;
+    ///
+    /// #[suzunari_error]
+    /// #[suzu(display("profile `{name}` not found"))]
+    /// struct ProfileError {
+    ///     name: String,
+    /// }
+    ///
+    /// let err = ProfileSnafu { name: "dev" }.build();
+    /// let report = StackReport::from_error(err).hide_locations();
+    /// assert_eq!(report.to_string(), "Error: ProfileError: profile `dev` not found");
+    /// ```
+    #[must_use]
+    pub fn hide_locations(mut self) -> Self {
+        self.options.hide_locations = true;
+        self
+    }
+
+    /// Omits the `TypeName: ` prefix from every frame, leaving only the
+    /// messages. Together with [`hide_locations`](Self::hide_locations),
+    /// this gives plain output for end users.
+    ///
+    /// ```
+    /// use suzunari_error::*;
+    ///
+    /// #[suzunari_error]
+    /// #[suzu(display("profile `{name}` not found"))]
+    /// struct ProfileError {
+    ///     name: String,
+    /// }
+    ///
+    /// let err = ProfileSnafu { name: "dev" }.build();
+    /// let report = StackReport::from_error(err)
+    ///     .hide_type_names()
+    ///     .hide_locations();
+    /// assert_eq!(report.to_string(), "Error: profile `dev` not found");
+    /// ```
+    #[must_use]
+    pub fn hide_type_names(mut self) -> Self {
+        self.options.hide_type_names = true;
+        self
+    }
+
+    /// Returns the number of frames the report prints, without rendering it,
+    /// or `0` for `Ok`.
+    ///
+    /// Counts the top-level error plus the causes, leaving out transparent
+    /// frames and the repeats dropped by
+    /// [`dedup_adjacent`](Self::dedup_adjacent), so it may be less than
+    /// `1 + `[`StackError::total_depth`]. Ignores
+    /// [`max_depth`](Self::max_depth): the count is for the untruncated
+    /// report. Useful for skipping empty or single-frame reports before
+    /// logging.
+    ///
+    /// Unlike `total_depth`, the count is bounded like the report's own
+    /// traversal, so a chain that loops back on itself counts as 129 frames.
+    #[must_use]
+    pub fn frame_count(&self) -> usize {
+        let Err(e) = &self.result else {
+            return 0;
+        };
+        let mut skip = frame_filter(&self.options);
+        let mut links = Links::new(e).take(MAX_CAUSES + 1);
+        let Some(top) = links.next() else {
+            return 0;
+        };
+        // Never skipped; this only records its message for the first cause.
+        skip(&top);
+        1 + links.filter(|link| !skip(link)).count()
+    }
+
+    /// Returns an iterator over the frames the report renders, top-level
+    /// error first, or an empty iterator for `Ok`.
+    ///
+    /// Yields the `stack_source()` chain as [`Link::StackFrame`]s, then the
+    /// plain `Error::source()` causes beyond it as [`Link::PlainFrame`]s, the
+    /// same two phases the report prints. Transparent frames, which the
+    /// report skips, are included; see [`Link::is_transparent`]. Ignores
+    /// [`max_depth`](Self::max_depth) and style settings, for renderers
+    /// that lay out the frames themselves.
+    ///
+    /// Lazy and unbounded: a chain that loops back on itself yields forever.
+    ///
+    /// ```
+    /// use suzunari_error::*;
+    ///
+    /// #[suzunari_error]
+    /// #[suzu(display("config unreadable"))]
+    /// struct ConfigError {
+    ///     source: std::io::Error,
+    /// }
+    ///
+    /// let err = std::fs::read("/nonexistent").context(ConfigSnafu).unwrap_err();
+    /// let report = StackReport::from_error(err);
+    /// let mut links = report.links();
+    /// assert!(matches!(
+    ///     links.next(),
+    ///     Some(Link::StackFrame { type_name: "ConfigError", .. })
+    /// ));
+    /// assert!(matches!(links.next(), Some(Link::PlainFrame { .. })));
+    /// assert!(links.next().is_none());
+    /// ```
+    #[must_use]
+    pub fn links(&self) -> Links<'_> {
+        match &self.result {
+            Ok(()) => Links::empty(),
+            Err(e) => Links::new(e),
+        }
+    }
+
+    /// Renders every frame's location with `f` instead of `file:line:column`.
+    ///
+    /// Applied uniformly to all frames at formatting time, e.g. to shorten
+    /// paths in ways [`strip_prefix`](Self::strip_prefix) cannot. Takes
+    /// precedence over [`strip_prefix`](Self::strip_prefix) and the
+    /// [`style`](Self::style) location settings (`hide_paths`, `location_uri`).
+    ///
+    /// ```
+    /// use suzunari_error::*;
+    ///
+    /// #[suzunari_error]
+    /// #[suzu(display("failed"))]
+    /// struct AppError {}
+    ///
+    /// let report = StackReport::from_error(AppSnafu.build())
+    ///     .map_locations(|loc| format!("line {}", loc.line()));
+    /// assert!(format!("{report}").contains(", at line "));
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn map_locations<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&Location) -> String + Send + Sync + 'static,
+    {
+        self.options.location_mapper = Some(Box::new(f));
+        self
+    }
+
+    /// Lays the report out with a custom [`ReportStyle`] instead of the
+    /// built-in format.
+    ///
+    /// [`max_depth`](Self::max_depth) still bounds the frames; the other
+    /// settings only affect the built-in format. With the `backtrace`
+    /// feature, the `Backtrace:` section is left to the style.
+    #[must_use]
+    pub fn with_style<S: ReportStyle>(self, style: S) -> StyledReport<E, S> {
+        StyledReport {
+            report: self,
+            style,
+        }
+    }
+
+    /// Replaces the presentation settings. See [`StackReportStyle`].
+    #[must_use]
+    pub fn style(mut self, style: StackReportStyle) -> Self {
+        self.options.style = style;
+        self
+    }
+}
+
+impl<E> StackReport<E> {
+    /// Unwraps the report back into the `Result` it was created from.
+    ///
+    /// Lets a report be propagated further with `?` after inspection,
+    /// so converting to `StackReport` is not a dead end. Builder settings
+    /// such as [`max_depth`](Self::max_depth) are discarded.
+    pub fn into_result(self) -> Result<(), E> {
+        self.result
+    }
+
+    /// Borrows the reported `Result` without consuming the report.
+    pub fn as_result(&self) -> Result<(), &E> {
+        self.result.as_ref().map(|_| ())
+    }
+
+    /// Returns the reported error, or `None` for a success report.
+    ///
+    /// Handy in tests for asserting on the concrete error instead of the
+    /// formatted output. Builder settings are discarded.
+    #[must_use]
+    pub fn err(self) -> Option<E> {
+        self.result.err()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<E: StackError> StackReport<E> {
+    /// Renders the report into a `String`, or an empty one for `Ok`.
+    ///
+    /// Same as `to_string()`, without importing `ToString` in `no_std`
+    /// crates that only enable `alloc`. Builder settings apply.
+    ///
+    /// ```
+    /// use suzunari_error::*;
+    ///
+    /// #[suzunari_error]
+    /// #[suzu(display("failed"))]
+    /// struct AppError {}
+    ///
+    /// let report = StackReport::from_error(AppSnafu.build());
+    /// assert_eq!(report.render(), report.to_string());
+    /// ```
+    #[must_use]
+    pub fn render(&self) -> String {
+        alloc::format!("{self}")
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<E: StackError + Send + Sync + 'static> StackReport<E> {
+    /// Boxes the reported error, or returns `None` for a success report.
+    ///
+    /// Bridges to [`BoxedStackError`](crate::BoxedStackError) for storing
+    /// heterogeneous errors. Builder settings are discarded.
+    #[must_use]
+    pub fn into_boxed_error(self) -> Option<crate::BoxedStackError> {
+        self.result.err().map(crate::BoxedStackError::new)
+    }
+}
+
+/// Converts a `Result<(), E>` into a [`StackReport`] with a method call.
+///
+/// `.into()` needs the target type spelled out at the call site;
+/// `into_report()` infers it from the error type.
+///
+/// ```
+/// use suzunari_error::*;
+///
+/// #[suzunari_error]
+/// #[suzu(display("sync failed"))]
+/// struct SyncError {}
+///
+/// fn sync() -> Result<(), SyncError> {
+///     ensure!(false, SyncSnafu);
+///     Ok(())
+/// }
+///
+/// let output = sync().into_report().max_depth(0).to_string();
+/// assert!(output.starts_with("Error: SyncError: sync failed, at "));
+/// ```
+pub trait ResultReportExt<E> {
+    /// Wraps `self` in a [`StackReport`].
+    #[must_use]
+    fn into_report(self) -> StackReport<E>;
+}
+
+impl<E: StackError> ResultReportExt<E> for Result<(), E> {
+    fn into_report(self) -> StackReport<E> {
+        StackReport::from(self)
+    }
+}
+
+/// A [`StackReport`] rendered with a custom [`ReportStyle`].
+///
+/// Created by [`StackReport::with_style`]. Like `StackReport`, `Display` and
+/// `Debug` print the same text, nothing for `Ok`, and with the `std`
+/// feature it can be returned from `main()`.
+pub struct StyledReport<E, S> {
+    report: StackReport<E>,
+    style: S,
+}
+
+impl<E, S> StyledReport<E, S> {
+    /// Returns the report without the style.
+    pub fn into_report(self) -> StackReport<E> {
+        self.report
+    }
+}
+
+impl<E: StackError, S: ReportStyle> StyledReport<E, S> {
+    /// Writes what the [`Termination`] impl prints to stderr, like
+    /// [`StackReport::write_to`].
+    #[cfg(feature = "std")]
+    pub fn write_to<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        if self.report.result.is_ok() {
+            return Ok(());
+        }
+        if self.report.options.exit_context {
+            write_exit_context(writer)?;
+        }
+        writeln!(writer, "{self}")
+    }
+}
+
+impl<E: StackError, S: ReportStyle> Display for StyledReport<E, S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match &self.report.result {
+            Ok(()) => Ok(()),
+            Err(e) => write_frames(f, e, &self.report.options, &self.style),
+        }
+    }
+}
+
+impl<E: StackError, S: ReportStyle> Debug for StyledReport<E, S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: StackError, S: ReportStyle> Termination for StyledReport<E, S> {
+    fn report(self) -> ExitCode {
+        if self.report.result.is_ok() {
+            return ExitCode::SUCCESS;
+        }
+        // Same contract as StackReport: ignore write errors.
+        let _ = self.write_to(&mut stderr());
+        ExitCode::FAILURE
+    }
+}
+
+/// One frame of an error chain, as yielded by [`StackReport::links`].
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub enum Link<'a> {
+    /// A `StackError` in the `stack_source()` chain. Its `Display` output
+    /// (`error`) is the frame's message.
+    StackFrame {
+        /// The error's [`StackError::type_name`].
+        type_name: &'static str,
+        /// Where the error was created.
+        location: Location,
+        /// The error itself, for its message, metadata, and module path.
+        error: &'a dyn StackError,
+    },
+    /// A plain `Error::source()` cause below the last `StackError`. Its
+    /// `Display` output (`error`) is the frame's message.
+    PlainFrame {
+        /// The error itself.
+        error: &'a (dyn Error + 'static),
+    },
+}
+
+impl Link<'_> {
+    /// Returns whether the frame is a [transparent](StackError::is_transparent)
+    /// `StackError`, which `StackReport` does not print.
+    #[must_use]
+    pub fn is_transparent(&self) -> bool {
+        match self {
+            Link::StackFrame { error, .. } => error.is_transparent(),
+            Link::PlainFrame { .. } => false,
+        }
+    }
+}
+
+/// Iterator over the frames of an error chain.
+///
+/// Created by [`StackReport::links`].
+#[derive(Debug, Clone)]
+pub struct Links<'a> {
+    next_stack: Option<&'a dyn StackError>,
+    next_plain: Option<&'a (dyn Error + 'static)>,
+}
+
+impl<'a> Links<'a> {
+    fn new(error: &'a dyn StackError) -> Self {
+        Self {
+            next_stack: Some(error),
+            next_plain: None,
+        }
+    }
+
+    fn empty() -> Self {
+        Self {
+            next_stack: None,
+            next_plain: None,
+        }
+    }
+}
+
+impl<'a> Iterator for Links<'a> {
+    type Item = Link<'a>;
+
+    fn next(&mut self) -> Option<Link<'a>> {
+        if let Some(error) = self.next_stack {
+            self.next_stack = error.stack_source();
+            match self.next_stack {
+                // Invariant: stack_source() implies source() (StackError is a sub-chain
+                // of Error). In release builds this assertion is stripped; a broken impl
+                // would produce truncated output (missing causes) rather than a panic,
+                // which is preferable to crashing inside a Display formatter.
+                Some(_) => debug_assert!(
+                    error.source().is_some(),
+                    "StackError::stack_source() returned Some but Error::source() returned None \
+                     for type {}. This indicates an incorrect StackError implementation.",
+                    error.type_name()
+                ),
+                // Phase 2: the plain Error chain continues below the last StackError.
+                None => self.next_plain = error.source(),
+            }
+            return Some(Link::StackFrame {
+                type_name: error.type_name(),
+                location: error.location(),
+                error,
+            });
+        }
+        let error = self.next_plain?;
+        self.next_plain = error.source();
+        Some(Link::PlainFrame { error })
+    }
+}
+
+impl FusedIterator for Links<'_> {}
 
 impl<E: StackError> From<Result<(), E>> for StackReport<E> {
     fn from(result: Result<(), E>) -> Self {
-        Self(result)
+        Self {
+            result,
+            options: ReportOptions::default(),
+        }
     }
 }
 
 impl<E: StackError> From<E> for StackReport<E> {
     fn from(error: E) -> Self {
-        Self(Err(error))
+        Self::from(Err(error))
     }
 }
 
@@ -91,9 +1107,30 @@ impl<E: StackError> Debug for StackReport<E> {
 
 impl<E: StackError> Display for StackReport<E> {
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
-        match &self.0 {
+        match &self.result {
             Ok(()) => Ok(()),
-            Err(e) => Display::fmt(&StackReportFormatter(e), f),
+            Err(e) => Display::fmt(
+                &StackReportFormatter {
+                    error: e,
+                    options: &self.options,
+                },
+                f,
+            ),
+        }
+    }
+}
+
+/// Lets a report stand in where `impl Error` is expected. `source()` returns
+/// the wrapped error, or `None` for the `Ok` case.
+///
+/// `Display` already renders the whole chain, so a consumer that also walks
+/// `source()` prints each cause twice; such consumers should use the error
+/// itself instead.
+impl<E: StackError + 'static> Error for StackReport<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.result {
+            Ok(()) => None,
+            Err(e) => Some(e),
         }
     }
 }
@@ -101,83 +1138,462 @@ impl<E: StackError> Display for StackReport<E> {
 #[cfg(feature = "std")]
 impl<E: StackError> Termination for StackReport<E> {
     fn report(self) -> ExitCode {
-        match self.0 {
-            Ok(()) => ExitCode::SUCCESS,
-            Err(e) => {
-                // Ignore write errors — stderr may be closed, and
-                // panicking here would mask the original error.
-                // Trailing `\n` is added here because Display omits it
-                // (Display convention: no trailing newline).
-                let _ = Write::write_fmt(
-                    &mut stderr(),
-                    format_args!("{}\n", StackReportFormatter(&e)),
-                );
-                ExitCode::FAILURE
-            }
+        if self.result.is_ok() {
+            return ExitCode::SUCCESS;
         }
+        // Ignore write errors — stderr may be closed, and
+        // panicking here would mask the original error.
+        let _ = self.write_to(&mut stderr());
+        ExitCode::FAILURE
     }
 }
 
-/// Internal formatter that formats a StackError chain.
-struct StackReportFormatter<'a>(&'a dyn StackError);
+/// Writes the `[<timestamp> thread '<name>']` line of
+/// [`StackReport::with_exit_context`].
+#[cfg(feature = "std")]
+fn write_exit_context<W: Write>(writer: &mut W) -> std::io::Result<()> {
+    let thread = std::thread::current();
+    writeln!(
+        writer,
+        "[{} thread '{}']",
+        UtcTimestamp(SystemTime::now()),
+        thread.name().unwrap_or("<unnamed>")
+    )
+}
 
-impl Display for StackReportFormatter<'_> {
+/// Formats a `SystemTime` as an ISO 8601 UTC timestamp with milliseconds.
+///
+/// Times before the Unix epoch print as the epoch.
+#[cfg(feature = "std")]
+struct UtcTimestamp(SystemTime);
+
+#[cfg(feature = "std")]
+impl Display for UtcTimestamp {
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
-        let error = self.0;
+        let since_epoch = self
+            .0
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap_or_default();
+        let secs = since_epoch.as_secs();
+        let (days, secs_of_day) = (secs / 86_400, secs % 86_400);
+
+        // Days since the epoch to a proleptic Gregorian date, after
+        // Howard Hinnant's `civil_from_days`, with eras starting on March 1.
+        let z = days + 719_468;
+        let era = z / 146_097;
+        let day_of_era = z % 146_097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let mp = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * mp + 2) / 5 + 1;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = year_of_era + era * 400 + u64::from(month <= 2);
 
-        // Top-level error with type name and location (no index).
-        // No trailing newline — Display convention.
         write!(
             f,
-            "Error: {}: {error}, at {}",
-            error.type_name(),
-            error.location()
-        )?;
-
-        // Check if there are any causes.
-        // source() suffices: the StackError contract guarantees that
-        // stack_source().is_some() implies source().is_some().
-        if error.source().is_none() {
-            return Ok(());
+            "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}.{:03}Z",
+            secs_of_day / 3600,
+            secs_of_day / 60 % 60,
+            secs_of_day % 60,
+            since_epoch.subsec_millis()
+        )
+    }
+}
+
+/// Upper bound on the causes a report traverses.
+///
+/// A `stack_source()`/`source()` chain can loop, e.g., through shared `Arc`s
+/// and interior mutability. Past this many causes the formatter stops with a
+/// `... (possible cycle)` line instead of spinning forever.
+const MAX_CAUSES: usize = 128;
+
+/// Internal formatter that formats a StackError chain.
+struct StackReportFormatter<'a> {
+    error: &'a dyn StackError,
+    options: &'a ReportOptions,
+}
+
+impl Display for StackReportFormatter<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let style = BuiltinStyle {
+            options: self.options,
+        };
+        write_frames(f, self.error, self.options, &style)?;
+        #[cfg(feature = "backtrace")]
+        write_backtrace(f, self.error)?;
+        if self.options.root_debug {
+            write_root_debug(f, self.error)?;
         }
+        Ok(())
+    }
+}
 
-        // Prefix each subsequent line with `\n` instead of appending trailing `\n`,
-        // so the overall output has no trailing newline.
-        write!(f, "\nCaused by (recent first):")?;
-
-        let mut index = 1;
-
-        // Phase 1: StackError chain (with location)
-        let mut current_stack: &dyn StackError = error;
-        while let Some(next) = current_stack.stack_source() {
-            // Invariant: stack_source() implies source() (StackError is a sub-chain of Error).
-            // In release builds this assertion is stripped; a broken impl would produce
-            // truncated output (missing causes) rather than a panic, which is preferable
-            // to crashing inside a Display formatter.
-            debug_assert!(
-                current_stack.source().is_some(),
-                "StackError::stack_source() returned Some but Error::source() returned None \
-                 for type {}. This indicates an incorrect StackError implementation.",
-                current_stack.type_name()
-            );
-            write!(
-                f,
-                "\n  {index}| {}: {next}, at {}",
-                next.type_name(),
-                next.location()
-            )?;
-            index += 1;
-            current_stack = next;
+/// Writes the `Debug` output of the deepest error in the chain of `error`,
+/// including plain causes, bounded by [`MAX_CAUSES`] like the frames.
+fn write_root_debug(f: &mut Formatter<'_>, error: &dyn StackError) -> core::fmt::Result {
+    match Links::new(error).take(MAX_CAUSES + 1).last() {
+        Some(Link::StackFrame { error, .. }) => write!(f, "\nRoot cause (debug): {error:?}"),
+        Some(Link::PlainFrame { error }) => write!(f, "\nRoot cause (debug): {error:?}"),
+        None => Ok(()),
+    }
+}
+
+/// Walks the chain of `error` and hands each printed frame to `style`.
+///
+/// The top-level error is frame `0`. Transparent frames, and repeated
+/// messages under [`StackReport::dedup_adjacent`], are walked through but
+/// not printed: `index` numbers the printed causes, while `visited` bounds
+/// the walk.
+fn write_frames(
+    f: &mut Formatter<'_>,
+    error: &dyn StackError,
+    options: &ReportOptions,
+    style: &dyn ReportStyle,
+) -> core::fmt::Result {
+    let mut skip = frame_filter(options);
+    let max_depth = options.max_depth.unwrap_or(usize::MAX);
+    let mut links = Links::new(error);
+    if let Some(top) = links.next() {
+        // Never skipped; this only records its message for the first cause.
+        skip(&top);
+        style.frame(0, f, top)?;
+    }
+    if max_depth == 0 {
+        return Ok(());
+    }
+
+    let mut index = 0;
+    let mut visited = 0;
+    while let Some(link) = links.next() {
+        visited += 1;
+        if visited > MAX_CAUSES {
+            return style.elision(index + 1, f, None);
+        }
+        if skip(&link) {
+            continue;
+        }
+        index += 1;
+        if index > max_depth {
+            let remaining = count_remaining(visited, links, skip);
+            return style.elision(index, f, remaining);
         }
+        style.frame(index, f, link)?;
+    }
+    Ok(())
+}
 
-        // Phase 2: Error chain (without location)
-        let mut current_error = current_stack.source();
-        while let Some(e) = current_error {
-            write!(f, "\n  {index}| {e}")?;
-            index += 1;
-            current_error = e.source();
+/// Returns a filter matching the frames walked through but not printed:
+/// transparent frames, and repeated messages under
+/// [`StackReport::dedup_adjacent`].
+///
+/// Stateful with `dedup_adjacent`, so it must see every frame in order,
+/// including the top-level error.
+fn frame_filter(options: &ReportOptions) -> impl FnMut(&Link<'_>) -> bool {
+    #[cfg(feature = "alloc")]
+    let mut dedup = options.dedup_adjacent.then(AdjacentDedup::default);
+    #[cfg(not(feature = "alloc"))]
+    let _ = options;
+    move |link: &Link<'_>| {
+        if link.is_transparent() {
+            return true;
         }
+        #[cfg(feature = "alloc")]
+        if let Some(dedup) = &mut dedup {
+            return dedup.repeats(link);
+        }
+        false
+    }
+}
 
-        Ok(())
+/// Counts the printed causes from the first elided one (already visited)
+/// through the end of `rest`, leaving out the frames `skip` matches.
+///
+/// Returns `None` once [`MAX_CAUSES`] frames have been visited in total,
+/// i.e., the chain possibly loops.
+fn count_remaining<'a>(
+    mut visited: usize,
+    rest: impl Iterator<Item = Link<'a>>,
+    mut skip: impl FnMut(&Link<'_>) -> bool,
+) -> Option<usize> {
+    let mut remaining = 1;
+    for link in rest {
+        if visited == MAX_CAUSES {
+            return None;
+        }
+        visited += 1;
+        if !skip(&link) {
+            remaining += 1;
+        }
+    }
+    Some(remaining)
+}
+
+/// The last printed message, for [`StackReport::dedup_adjacent`].
+#[cfg(feature = "alloc")]
+#[derive(Default)]
+struct AdjacentDedup {
+    previous: Option<String>,
+}
+
+#[cfg(feature = "alloc")]
+impl AdjacentDedup {
+    /// Returns whether `link` is a plain cause with the same message as the
+    /// previous printed frame, and records its message otherwise.
+    fn repeats(&mut self, link: &Link<'_>) -> bool {
+        use alloc::string::ToString;
+
+        let (message, plain) = match link {
+            Link::StackFrame { error, .. } => (error.to_string(), false),
+            Link::PlainFrame { error } => (error.to_string(), true),
+        };
+        if plain && self.previous.as_deref() == Some(message.as_str()) {
+            return true;
+        }
+        self.previous = Some(message);
+        false
+    }
+}
+
+/// The layout of [`StackReport`]'s own `Display`, honoring its builder
+/// settings.
+struct BuiltinStyle<'a> {
+    options: &'a ReportOptions,
+}
+
+impl ReportStyle for BuiltinStyle<'_> {
+    fn frame(&self, index: usize, f: &mut Formatter<'_>, frame: Link<'_>) -> core::fmt::Result {
+        let options = self.options;
+        if index == 0 {
+            // Top-level error (no index). No trailing newline — Display convention.
+            f.write_str(options.header_label.unwrap_or("Error"))?;
+            f.write_str(": ")?;
+        } else {
+            if index == 1 {
+                write_causes_header(f, options)?;
+            }
+            write_cause_prefix(f, index, options.index_style)?;
+        }
+        match frame {
+            // Phase 1: StackError chain (with location)
+            Link::StackFrame {
+                type_name,
+                location,
+                error,
+            } => {
+                if !options.hide_type_names {
+                    write!(f, "{type_name}: ")?;
+                }
+                write!(f, "{error}")?;
+                if !options.hide_locations {
+                    f.write_str(", at ")?;
+                    write_location(f, location, options)?;
+                }
+                write_module_path(f, error, &options.style)?;
+                write_metadata(f, error)
+            }
+            // Phase 2: Error chain (without location)
+            Link::PlainFrame { error } => write!(f, "{error}"),
+        }
+    }
+
+    fn elision(
+        &self,
+        index: usize,
+        f: &mut Formatter<'_>,
+        remaining: Option<usize>,
+    ) -> core::fmt::Result {
+        if index == 1 {
+            write_causes_header(f, self.options)?;
+        }
+        write_ellipsis_prefix(f, index)?;
+        match remaining {
+            Some(remaining) => write!(f, "... ({remaining} more)"),
+            None => f.write_str("... (possible cycle)"),
+        }
+    }
+}
+
+/// Writes the backtrace of the deepest error in the `stack_source()` chain
+/// that captured one, as a trailing `Backtrace:` section.
+#[cfg(feature = "backtrace")]
+fn write_backtrace(f: &mut Formatter<'_>, error: &dyn StackError) -> core::fmt::Result {
+    use std::backtrace::BacktraceStatus;
+    use std::string::ToString;
+
+    let mut deepest = None;
+    let mut current = Some(error);
+    // Bounded like the chain itself, so a cyclic chain cannot hang here.
+    for _ in 0..=MAX_CAUSES {
+        let Some(e) = current else {
+            break;
+        };
+        if let Some(bt) = e
+            .backtrace()
+            .filter(|bt| bt.status() == BacktraceStatus::Captured)
+        {
+            deepest = Some(bt);
+        }
+        current = e.stack_source();
+    }
+    match deepest {
+        // Backtrace's Display ends each frame with `\n`; trim it to keep the
+        // report free of trailing newlines.
+        Some(bt) => write!(f, "\nBacktrace:\n{}", bt.to_string().trim_end()),
+        None => Ok(()),
+    }
+}
+
+/// Writes `location` via the location mapper if set, otherwise as plain
+/// `file:line:column` or as a link, depending on `style`.
+fn write_location(
+    f: &mut Formatter<'_>,
+    location: Location,
+    options: &ReportOptions,
+) -> core::fmt::Result {
+    #[cfg(feature = "alloc")]
+    if let Some(mapper) = &options.location_mapper {
+        return f.write_str(&mapper(&location));
+    }
+    let style = &options.style;
+    if style.hide_paths {
+        let file = location.file();
+        // Handles both separators: `file!()` paths use `\` on Windows hosts.
+        let file_name = file.rsplit(['/', '\\']).next().unwrap_or(file);
+        return write!(f, "{file_name}:{}:{}", location.line(), location.column());
+    }
+    #[cfg(feature = "std")]
+    if let Some(scheme) = &style.location_uri {
+        return write_location_uri(f, location, scheme);
+    }
+    match options.strip_prefix {
+        Some(prefix) => write!(
+            f,
+            "{}:{}:{}",
+            strip_path_prefix(location.file(), prefix),
+            location.line(),
+            location.column()
+        ),
+        None => write!(f, "{location}"),
+    }
+}
+
+/// Removes `prefix` and the separator after it from `file`, if `prefix`
+/// covers whole leading components; otherwise returns `file` unchanged.
+fn strip_path_prefix<'a>(file: &'a str, prefix: &str) -> &'a str {
+    let prefix = prefix.trim_end_matches(['/', '\\']);
+    if prefix.is_empty() {
+        return file;
+    }
+    match file.strip_prefix(prefix) {
+        Some(rest) if rest.starts_with(['/', '\\']) => rest.trim_start_matches(['/', '\\']),
+        _ => file,
+    }
+}
+
+#[cfg(feature = "std")]
+fn write_location_uri(
+    f: &mut Formatter<'_>,
+    location: Location,
+    scheme: &LocationUriScheme,
+) -> core::fmt::Result {
+    let url = LocationUrl { location, scheme };
+    match scheme {
+        LocationUriScheme::Osc8 { .. } => write!(f, "\x1b]8;;{url}\x1b\\{location}\x1b]8;;\x1b\\"),
+        LocationUriScheme::VsCode { .. } | LocationUriScheme::Template { .. } => {
+            write!(f, "{url}")
+        }
+    }
+}
+
+/// Writes ` in <module path>` if enabled by `style` and known for the frame.
+fn write_module_path(
+    f: &mut Formatter<'_>,
+    error: &dyn StackError,
+    style: &StackReportStyle,
+) -> core::fmt::Result {
+    match error.module_path() {
+        Some(module_path) if style.show_module_path => write!(f, " in {module_path}"),
+        _ => Ok(()),
+    }
+}
+
+/// Writes the frame's metadata as ` (key=value, ...)`, or nothing if it has none.
+fn write_metadata(f: &mut Formatter<'_>, error: &dyn StackError) -> core::fmt::Result {
+    let mut result = Ok(());
+    let mut count = 0;
+    error.metadata(&mut |key, value| {
+        if result.is_ok() {
+            let separator = if count == 0 { " (" } else { ", " };
+            result = write!(f, "{separator}{key}={value}");
+        }
+        count += 1;
+    });
+    result?;
+    if count > 0 {
+        f.write_str(")")?;
+    }
+    Ok(())
+}
+
+/// Writes the line that introduces the causes, unless the tree layout
+/// (`{:#}`) shows causation through indentation instead.
+///
+/// Each line is prefixed with `\n` instead of appending a trailing `\n`,
+/// so the overall output has no trailing newline.
+fn write_causes_header(f: &mut Formatter<'_>, options: &ReportOptions) -> core::fmt::Result {
+    if f.alternate() {
+        return Ok(());
+    }
+    let label = options.causes_label.unwrap_or("Caused by (recent first)");
+    write!(f, "\n{label}:")
+}
+
+/// Starts the line of the `index`-th cause: `\n  {index}| ` normally, or a
+/// `╰─▶ ` connector nested one level deeper than its parent under `{:#}`.
+fn write_cause_prefix(f: &mut Formatter<'_>, index: usize, style: IndexStyle) -> core::fmt::Result {
+    if f.alternate() {
+        return write_tree_connector(f, index);
+    }
+    match style {
+        IndexStyle::Pipe => write!(f, "\n  {index}| "),
+        IndexStyle::Bracket => write!(f, "\n  [{index}] "),
+        IndexStyle::Dot => write!(f, "\n  {index}. "),
+    }
+}
+
+/// Starts an elision or cycle line standing in for the `index`-th cause.
+fn write_ellipsis_prefix(f: &mut Formatter<'_>, index: usize) -> core::fmt::Result {
+    if f.alternate() {
+        write_tree_connector(f, index)
+    } else {
+        f.write_str("\n  ")
+    }
+}
+
+fn write_tree_connector(f: &mut Formatter<'_>, index: usize) -> core::fmt::Result {
+    f.write_str("\n")?;
+    for _ in 1..index {
+        f.write_str("    ")?;
+    }
+    f.write_str("╰─▶ ")
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::UtcTimestamp;
+    use std::string::ToString;
+    use std::time::{Duration, SystemTime};
+
+    fn at(millis: u64) -> std::string::String {
+        UtcTimestamp(SystemTime::UNIX_EPOCH + Duration::from_millis(millis)).to_string()
+    }
+
+    #[test]
+    fn test_utc_timestamp() {
+        assert_eq!(at(0), "1970-01-01T00:00:00.000Z");
+        assert_eq!(at(951_782_400_000), "2000-02-29T00:00:00.000Z");
+        assert_eq!(at(1_700_000_000_123), "2023-11-14T22:13:20.123Z");
+        assert_eq!(at(4_107_542_399_999), "2100-02-28T23:59:59.999Z");
     }
 }
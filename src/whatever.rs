@@ -0,0 +1,242 @@
+use alloc::boxed::Box;
+use alloc::string::String;
+
+use crate::{BoxedStackError, Location, SharedStackError, StackError};
+use core::error::Error;
+use core::fmt::{Debug, Display, Formatter, Result};
+
+/// Stringly-typed error for prototypes, the counterpart of [`snafu::Whatever`].
+///
+/// Carries a message, the location where it was created, and an optional
+/// source. Create one with [`whatever_stack!`](crate::whatever_stack) or
+/// with `.whatever_context(...)` / `.with_whatever_context(...)` from the
+/// re-exported [`ResultExt`](crate::ResultExt), which builds it through
+/// [`snafu::FromString`]. snafu's own `whatever!` works too.
+///
+/// A source that is a [`BoxedStackError`] or [`SharedStackError`] is
+/// returned from [`stack_source`](StackError::stack_source), so its
+/// locations appear in the report; any other source is reported as a plain
+/// error. Reports `"Whatever"` as its type name.
+///
+/// ```
+/// use suzunari_error::*;
+///
+/// fn read_port(raw: &str) -> Result<u16, SuzunariWhatever> {
+///     raw.parse::<u16>()
+///         .whatever_context(format!("invalid port {raw:?}"))
+/// }
+///
+/// let err = read_port("http").unwrap_err();
+/// assert_eq!(err.to_string(), "invalid port \"http\"");
+/// assert_eq!(err.type_name(), "Whatever");
+/// assert!(std::error::Error::source(&err).is_some());
+/// ```
+pub struct SuzunariWhatever {
+    message: String,
+    source: Option<Box<dyn Error + Send + Sync>>,
+    location: Location,
+}
+
+impl SuzunariWhatever {
+    /// Creates an error with `message` and no source, located at the caller.
+    #[must_use]
+    #[track_caller]
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            source: None,
+            location: core::panic::Location::caller(),
+        }
+    }
+
+    /// Creates an error with `message` wrapping `source`, located at the caller.
+    #[must_use]
+    #[track_caller]
+    pub fn with_source(
+        source: impl Into<Box<dyn Error + Send + Sync>>,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            message: message.into(),
+            source: Some(source.into()),
+            location: core::panic::Location::caller(),
+        }
+    }
+
+    /// Returns the message.
+    #[must_use]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl Display for SuzunariWhatever {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl Debug for SuzunariWhatever {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_struct("SuzunariWhatever")
+            .field("message", &self.message)
+            .field("source", &self.source)
+            .field("location", &self.location)
+            .finish()
+    }
+}
+
+impl Error for SuzunariWhatever {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source.as_deref().map(|source| source as _)
+    }
+}
+
+impl StackError for SuzunariWhatever {
+    fn location(&self) -> Location {
+        self.location
+    }
+    fn type_name(&self) -> &'static str {
+        "Whatever"
+    }
+    fn stack_source(&self) -> Option<&dyn StackError> {
+        let source = self.source.as_deref()?;
+        if let Some(boxed) = source.downcast_ref::<BoxedStackError>() {
+            Some(boxed)
+        } else if let Some(shared) = source.downcast_ref::<SharedStackError>() {
+            Some(shared)
+        } else {
+            None
+        }
+    }
+}
+
+impl snafu::FromString for SuzunariWhatever {
+    type Source = Box<dyn Error + Send + Sync>;
+
+    #[track_caller]
+    fn without_source(message: String) -> Self {
+        Self::new(message)
+    }
+
+    #[track_caller]
+    fn with_source(source: Self::Source, message: String) -> Self {
+        Self::with_source(source, message)
+    }
+}
+
+impl From<SuzunariWhatever> for BoxedStackError {
+    fn from(error: SuzunariWhatever) -> Self {
+        Self::new(error)
+    }
+}
+
+/// Returns early with a [`SuzunariWhatever`] built from a format string,
+/// located at the call site.
+///
+/// Mirrors snafu's `whatever!`: `whatever_stack!("msg {}", x)` returns
+/// `Err(..)`, and `whatever_stack!(result, "msg {}", x)` unwraps `result` or
+/// returns its error wrapped as the source. The error is converted with
+/// `Into`, so the function may also return [`BoxedStackError`].
+///
+/// ```
+/// use suzunari_error::*;
+///
+/// fn check(len: usize) -> Result<(), SuzunariWhatever> {
+///     if len > 8 {
+///         whatever_stack!("length {} exceeds limit", len);
+///     }
+///     Ok(())
+/// }
+///
+/// fn parse(raw: &str) -> Result<u8, BoxedStackError> {
+///     let value = whatever_stack!(raw.parse::<u8>(), "not a byte: {raw}");
+///     Ok(value)
+/// }
+///
+/// assert_eq!(check(9).unwrap_err().to_string(), "length 9 exceeds limit");
+/// assert_eq!(parse("300").unwrap_err().type_name(), "Whatever");
+/// ```
+#[macro_export]
+macro_rules! whatever_stack {
+    ($fmt:literal $(, $arg:expr)* $(,)?) => {
+        return ::core::result::Result::Err(::core::convert::Into::into(
+            $crate::SuzunariWhatever::new($crate::__private::format!($fmt $(, $arg)*)),
+        ))
+    };
+    ($source:expr, $fmt:literal $(, $arg:expr)* $(,)?) => {
+        match $source {
+            ::core::result::Result::Ok(value) => value,
+            ::core::result::Result::Err(error) => {
+                return ::core::result::Result::Err(::core::convert::Into::into(
+                    $crate::SuzunariWhatever::with_source(
+                        error,
+                        $crate::__private::format!($fmt $(, $arg)*),
+                    ),
+                ));
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::StackReport;
+    use alloc::format;
+    use alloc::string::ToString;
+    use snafu::ResultExt;
+
+    #[test]
+    fn test_whatever_stack_macro() {
+        let fail = |limit: u32| -> core::result::Result<(), SuzunariWhatever> {
+            whatever_stack!("limit {} reached", limit);
+        };
+        let line = line!() - 2;
+
+        let err = fail(3).unwrap_err();
+        assert_eq!(err.to_string(), "limit 3 reached");
+        assert_eq!(err.message(), "limit 3 reached");
+        assert_eq!(err.location().file(), file!());
+        assert_eq!(err.location().line(), line);
+        assert!(err.source().is_none());
+
+        let report = format!("{}", StackReport::from_error(err));
+        assert!(report.starts_with("Error: Whatever: limit 3 reached, at "));
+        assert!(report.contains(&format!("{}:{line}:", file!())));
+    }
+
+    #[test]
+    fn test_whatever_context_keeps_source() {
+        let result: core::result::Result<u8, SuzunariWhatever> =
+            "x".parse::<u8>().whatever_context("bad byte");
+        let line = line!() - 1;
+
+        let err = result.unwrap_err();
+        assert_eq!(err.to_string(), "bad byte");
+        assert_eq!(err.location().line(), line);
+        assert!(err.source().unwrap().is::<core::num::ParseIntError>());
+        assert!(err.stack_source().is_none());
+
+        let report = format!("{}", StackReport::from_error(err));
+        assert!(report.contains("Whatever: bad byte, at "));
+        assert!(report.contains("invalid digit found in string"));
+    }
+
+    #[test]
+    fn test_whatever_stack_source_sees_boxed_stack_error() {
+        fn inner() -> core::result::Result<(), BoxedStackError> {
+            whatever_stack!("inner failed");
+        }
+        fn outer() -> core::result::Result<(), BoxedStackError> {
+            whatever_stack!(inner(), "outer failed");
+            Ok(())
+        }
+
+        let err = outer().unwrap_err();
+        assert_eq!(err.type_name(), "Whatever");
+        let inner = err.stack_source().unwrap();
+        assert_eq!(inner.to_string(), "inner failed");
+        assert_eq!(inner.type_name(), "Whatever");
+    }
+}
@@ -0,0 +1,6 @@
+//! Test-only crate for `#[suzu(crate = ...)]`. Tests live in `tests/`.
+
+/// Facade module re-exporting suzunari-error under another path.
+pub mod facade {
+    pub use errors as suzunari;
+}
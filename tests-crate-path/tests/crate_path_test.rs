@@ -0,0 +1,49 @@
+use errors::{ResultExt, StackError, StackReport, suzunari_error};
+
+// Displayable library error that does not implement Error.
+#[derive(Debug)]
+struct LibError(&'static str);
+
+impl std::fmt::Display for LibError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+#[suzunari_error]
+#[suzu(crate = ::errors, display("read failed"))]
+struct ReadError {
+    #[suzu(from)]
+    source: LibError,
+}
+
+#[suzunari_error]
+#[suzu(crate = suzunari_error_crate_path_tests::facade::suzunari)]
+enum AppError {
+    #[suzu(display("load failed"))]
+    Load { source: ReadError },
+    #[suzu(display("unavailable"))]
+    Unavailable,
+}
+
+#[test]
+fn test_crate_override_through_rename() {
+    let err = Err::<(), _>(LibError("disk gone"))
+        .context(ReadSnafu)
+        .context(LoadSnafu)
+        .unwrap_err();
+    assert_eq!(err.type_name(), "AppError::Load");
+    assert_eq!(err.stack_source().unwrap().type_name(), "ReadError");
+    assert!(err.location().file().ends_with("crate_path_test.rs"));
+
+    let report = StackReport::from_error(err).to_string();
+    assert!(report.starts_with("Error: AppError::Load: load failed, at "));
+    assert!(report.contains("1| ReadError: read failed, at "));
+    assert!(report.contains("2| disk gone"));
+}
+
+#[test]
+fn test_crate_override_unit_variant() {
+    let err = UnavailableSnafu.build();
+    assert_eq!(err.type_name(), "AppError::Unavailable");
+}
@@ -65,6 +65,12 @@ mod alloc_tests {
     fn _use_from(e: super::CoreOnlyError) {
         let _: BoxedStackError = e.into();
     }
+
+    // StackReport::from_into converts through that From impl
+    fn _use_from_into(e: super::CoreOnlyError) {
+        let _: suzunari_error::StackReport<BoxedStackError> =
+            suzunari_error::StackReport::from_into(e);
+    }
 }
 
 // --- std tier ---
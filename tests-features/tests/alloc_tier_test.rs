@@ -159,3 +159,40 @@ fn test_stack_report_format_alloc_only() {
     assert!(report.contains("Error: InnerAllocError: inner alloc error"));
     assert!(report.contains("at"));
 }
+
+#[test]
+fn test_stack_error_macro_alloc_only() {
+    let code = 7;
+    let err = stack_error!("failed with code {code}");
+
+    assert_eq!(alloc::format!("{err}"), "failed with code 7");
+    assert_eq!(err.type_name(), "Message");
+}
+
+#[test]
+fn test_stack_report_render_alloc_only() {
+    fn fail() -> Result<(), InnerAllocError> {
+        ensure!(false, InnerAllocSnafu);
+        Ok(())
+    }
+    let inner_op = || fail().map_err(BoxedStackError::new);
+    let err = inner_op().context(OuterAllocSnafu).unwrap_err();
+    let (outer, inner) = (err.location(), err.stack_source().unwrap().location());
+
+    let report = StackReport::from_error(err);
+    let rendered: alloc::string::String = report.render();
+    assert_eq!(
+        rendered,
+        alloc::format!(
+            "Error: OuterAllocError: outer alloc error, at {outer}\n\
+             Caused by (recent first):\n  \
+             1| InnerAllocError: inner alloc error, at {inner}"
+        )
+    );
+    // `ToString` via `Display` is available in this tier as well.
+    assert_eq!(alloc::string::ToString::to_string(&report), rendered);
+    assert_eq!(
+        StackReport::from_result(Ok::<(), InnerAllocError>(())).render(),
+        ""
+    );
+}
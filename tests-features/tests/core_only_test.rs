@@ -30,16 +30,28 @@ impl StackError for CoreTestError {
 }
 
 // Simple stack buffer for core::fmt::Write (no alloc needed).
+// `limit` caps the usable capacity to simulate short buffers.
 struct StackBuf {
     buf: [u8; 512],
     len: usize,
+    limit: usize,
+    // Writes attempted after a write already failed; a formatter that
+    // ignores an error keeps writing.
+    writes_after_error: usize,
+    failed: bool,
 }
 
 impl StackBuf {
     fn new() -> Self {
+        Self::with_limit(512)
+    }
+    fn with_limit(limit: usize) -> Self {
         Self {
             buf: [0; 512],
             len: 0,
+            limit,
+            writes_after_error: 0,
+            failed: false,
         }
     }
     fn as_str(&self) -> &str {
@@ -49,9 +61,13 @@ impl StackBuf {
 
 impl core::fmt::Write for StackBuf {
     fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        if self.failed {
+            self.writes_after_error += 1;
+        }
         let bytes = s.as_bytes();
-        let remaining = self.buf.len() - self.len;
+        let remaining = self.limit - self.len;
         if bytes.len() > remaining {
+            self.failed = true;
             return Err(core::fmt::Error);
         }
         self.buf[self.len..self.len + bytes.len()].copy_from_slice(bytes);
@@ -76,6 +92,31 @@ fn test_stack_report_core_only() {
     assert!(buf.as_str().contains("at"));
 }
 
+// Static error tables register errors as `&'static dyn StackError`.
+static STATIC_ERROR: CoreTestError = CoreTestError {
+    location: core::panic::Location::caller(),
+};
+
+#[test]
+fn test_stack_report_static_dyn_stack_error() {
+    use core::fmt::Write;
+
+    let error: &'static dyn StackError = &STATIC_ERROR;
+    assert_eq!(error.type_name(), "CoreTestError");
+    assert_eq!(error.depth(), 0);
+
+    let mut expected = StackBuf::new();
+    write!(
+        expected,
+        "Error: CoreTestError: core error, at {}",
+        error.location()
+    )
+    .unwrap();
+    let mut buf = StackBuf::new();
+    write!(buf, "{}", StackReport::from(error)).unwrap();
+    assert_eq!(buf.as_str(), expected.as_str());
+}
+
 #[test]
 fn test_display_error_new_and_into_inner() {
     let wrapped = DisplayError::new("test");
@@ -143,3 +184,74 @@ fn test_from_returns_none_source_core_only() {
     let err: &dyn core::error::Error = &wrapped;
     assert!(err.source().is_none());
 }
+
+// --- Write errors propagate through StackReport's Display ---
+
+#[suzunari_error]
+#[suzu(display("disk read failed"))]
+struct DiskReadError {
+    #[suzu(meta)]
+    sector: u32,
+    #[suzu(from)]
+    source: RealOuter,
+}
+
+#[suzunari_error]
+#[suzu(display("load failed"))]
+struct LoadError {
+    #[suzu(meta)]
+    attempt: u8,
+    source: DiskReadError,
+}
+
+fn load_error() -> LoadError {
+    Err::<(), _>(RealOuter(RealInner))
+        .context(DiskReadSnafu { sector: 7u32 })
+        .context(LoadSnafu { attempt: 3u8 })
+        .unwrap_err()
+}
+
+// Formats the report into buffers of every length shorter than the full
+// output: each must fail, stop writing at the first error, and hold a prefix.
+fn assert_short_buffers_fail(report: &StackReport<LoadError>) {
+    use core::fmt::Write;
+
+    let mut full = StackBuf::new();
+    write!(full, "{report}").unwrap();
+    let expected = full.as_str();
+
+    for limit in 0..expected.len() {
+        let mut buf = StackBuf::with_limit(limit);
+        assert!(
+            write!(buf, "{report}").is_err(),
+            "limit {limit} should not fit {expected:?}"
+        );
+        assert_eq!(buf.writes_after_error, 0, "kept writing at limit {limit}");
+        assert!(expected.starts_with(buf.as_str()));
+    }
+
+    let mut exact = StackBuf::with_limit(expected.len());
+    write!(exact, "{report}").unwrap();
+    assert_eq!(exact.as_str(), expected);
+}
+
+#[test]
+fn test_short_buffer_propagates_error() {
+    let report = StackReport::from_error(load_error());
+    assert_short_buffers_fail(&report);
+}
+
+#[test]
+fn test_short_buffer_propagates_error_with_elision() {
+    let report = StackReport::from_error(load_error()).max_depth(1);
+    assert_short_buffers_fail(&report);
+}
+
+#[test]
+fn test_short_buffer_propagates_error_with_style() {
+    let mut style = StackReportStyle::default();
+    style.hide_paths = true;
+    style.show_module_path = true;
+    let report = StackReport::from_error(load_error()).style(style);
+    assert_short_buffers_fail(&report);
+}
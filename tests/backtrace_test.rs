@@ -0,0 +1,96 @@
+#![cfg(feature = "backtrace")]
+//! Tests for `StackError::backtrace()` and the `Backtrace:` report section.
+//!
+//! Snafu captures `Backtrace` fields with `Backtrace::force_capture()`, so these
+//! tests do not depend on `RUST_BACKTRACE`. `Option<Backtrace>` fields are
+//! constructed directly for the same reason.
+
+use std::backtrace::{Backtrace, BacktraceStatus};
+use suzunari_error::*;
+
+#[suzunari_error]
+#[suzu(display("leaf failed"))]
+struct LeafError {
+    #[suzu(backtrace)]
+    trace: Backtrace,
+}
+
+#[suzunari_error]
+#[suzu(display("outer failed"))]
+struct OuterError {
+    source: LeafError,
+}
+
+// A field named `backtrace` is picked up without a marker, like in snafu.
+#[suzunari_error]
+enum NamedBacktraceError {
+    #[suzu(display("with backtrace"))]
+    WithBacktrace { backtrace: Option<Backtrace> },
+    #[suzu(display("without backtrace"))]
+    WithoutBacktrace,
+}
+
+#[test]
+fn test_backtrace_is_captured() {
+    let err = LeafSnafu.build();
+    let bt = err.backtrace().expect("backtrace field should be exposed");
+    assert_eq!(bt.status(), BacktraceStatus::Captured);
+    assert!(!bt.to_string().is_empty());
+}
+
+#[test]
+fn test_backtrace_defaults_to_none() {
+    let err = WithoutBacktraceSnafu.build();
+    assert!(err.backtrace().is_none());
+}
+
+#[test]
+fn test_backtrace_named_field_option() {
+    let err = NamedBacktraceError::WithBacktrace {
+        backtrace: Some(Backtrace::force_capture()),
+        location: core::panic::Location::caller(),
+    };
+    assert!(err.backtrace().is_some());
+
+    let err = NamedBacktraceError::WithBacktrace {
+        backtrace: None,
+        location: core::panic::Location::caller(),
+    };
+    assert!(err.backtrace().is_none());
+}
+
+#[test]
+fn test_report_prints_deepest_backtrace() {
+    fn leaf() -> Result<(), LeafError> {
+        LeafSnafu.fail()
+    }
+    let err = leaf().context(OuterSnafu).unwrap_err();
+    // The outer error has no backtrace; the report uses the leaf's.
+    assert!(err.backtrace().is_none());
+    let leaf_trace = err.stack_source().unwrap().backtrace().unwrap().to_string();
+
+    let report = format!("{}", StackReport::from_error(err));
+    let (chain, trace) = report
+        .split_once("\nBacktrace:\n")
+        .expect("report should contain a backtrace section");
+    assert!(chain.starts_with("Error: OuterError: outer failed, at "));
+    assert!(chain.contains("1| LeafError: leaf failed, at "));
+    assert_eq!(trace, leaf_trace.trim_end());
+    assert!(!report.ends_with('\n'));
+}
+
+#[test]
+fn test_report_without_captured_backtrace_has_no_section() {
+    let err = NamedBacktraceError::WithBacktrace {
+        backtrace: None,
+        location: core::panic::Location::caller(),
+    };
+    let report = format!("{}", StackReport::from_error(err));
+    assert!(!report.contains("\nBacktrace:"), "{report:?}");
+}
+
+#[test]
+fn test_boxed_stack_error_delegates_backtrace() {
+    let err = BoxedStackError::new(LeafSnafu.build());
+    assert!(err.backtrace().is_some());
+}
@@ -0,0 +1,13 @@
+// derive(StackError) cannot inject a location into unit variants
+use suzunari_error::{Location, StackError};
+
+#[derive(Debug, snafu::Snafu, StackError)]
+pub enum MyError {
+    Named {
+        #[snafu(implicit)]
+        location: Location,
+    },
+    Unit,
+}
+
+fn main() {}
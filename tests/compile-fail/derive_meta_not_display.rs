@@ -0,0 +1,16 @@
+// #[stack(meta)] fields must implement Display
+use suzunari_error::{Location, StackError};
+
+#[derive(Debug)]
+struct NotDisplay;
+
+#[derive(Debug, suzunari_error::snafu::Snafu, StackError)]
+#[snafu(crate_root(suzunari_error::snafu))]
+struct MyError {
+    #[stack(meta)]
+    value: NotDisplay,
+    #[snafu(implicit)]
+    location: Location,
+}
+
+fn main() {}
@@ -1,4 +1,4 @@
-// #[stack(location)] on a non-Location field should fail.
+// #[stack(location)] on a type without AsLocation should fail.
 use suzunari_error::*;
 
 #[derive(Debug, snafu::Snafu, StackError)]
@@ -0,0 +1,14 @@
+// #[stack(meta)] needs a field name to use as the metadata key
+use suzunari_error::{Location, StackError};
+
+#[derive(Debug, StackError)]
+pub struct TupleError(#[stack(meta)] u32, Location);
+
+impl core::fmt::Display for TupleError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("tuple error")
+    }
+}
+impl core::error::Error for TupleError {}
+
+fn main() {}
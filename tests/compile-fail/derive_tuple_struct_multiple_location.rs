@@ -0,0 +1,14 @@
+// derive(StackError) on a tuple struct requires exactly one Location field
+use suzunari_error::{Location, StackError};
+
+#[derive(Debug, StackError)]
+pub struct TupleError(Location, Location);
+
+impl core::fmt::Display for TupleError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("tuple error")
+    }
+}
+impl core::error::Error for TupleError {}
+
+fn main() {}
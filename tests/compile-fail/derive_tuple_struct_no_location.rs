@@ -0,0 +1,14 @@
+// derive(StackError) on a tuple struct without a Location field
+use suzunari_error::StackError;
+
+#[derive(Debug, StackError)]
+pub struct TupleError(String);
+
+impl core::fmt::Display for TupleError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl core::error::Error for TupleError {}
+
+fn main() {}
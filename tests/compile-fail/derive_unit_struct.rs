@@ -0,0 +1,14 @@
+// derive(StackError) on a unit struct has no Location field to use
+use suzunari_error::StackError;
+
+#[derive(Debug, StackError)]
+pub struct UnitError;
+
+impl core::fmt::Display for UnitError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("unit error")
+    }
+}
+impl core::error::Error for UnitError {}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use suzunari_error::{Location, StackError};
+
+#[derive(Debug, StackError)]
+struct MissingSnafuError {
+    #[stack(location)]
+    location: Location,
+}
+
+fn main() {}
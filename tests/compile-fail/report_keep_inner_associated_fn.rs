@@ -0,0 +1,19 @@
+// #[report(keep_inner = ...)] on a receiver-less associated fn needs
+// `keep_inner = Self::name`; the bare name does not resolve inside the impl,
+// and the error points at it.
+use suzunari_error::*;
+
+#[suzunari_error]
+#[suzu(display("error"))]
+struct MyError {}
+
+struct App;
+
+impl App {
+    #[suzunari_error::report(keep_inner = run_inner)]
+    fn run(_n: u32) -> Result<(), MyError> {
+        Ok(())
+    }
+}
+
+fn main() {}
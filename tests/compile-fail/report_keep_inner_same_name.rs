@@ -0,0 +1,13 @@
+// #[report(keep_inner = ...)] must not reuse the wrapped function's name.
+use suzunari_error::*;
+
+#[suzunari_error]
+#[snafu(display("error"))]
+struct MyError {}
+
+#[suzunari_error::report(keep_inner = run)]
+fn run() -> Result<(), MyError> {
+    Ok(())
+}
+
+fn main() {}
@@ -1,4 +1,4 @@
-// #[report] does not accept arguments
+// #[report] only accepts `keep_inner = name`
 use suzunari_error::*;
 
 #[suzunari_error]
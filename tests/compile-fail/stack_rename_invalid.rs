@@ -0,0 +1,24 @@
+// `#[stack(rename ...)]` requires a non-empty string literal
+use snafu::Snafu;
+use suzunari_error::{Location, StackError};
+
+#[derive(Debug, Snafu, StackError)]
+enum MyError {
+    #[stack(rename = 42)]
+    NotAString {
+        #[snafu(implicit)]
+        location: Location,
+    },
+    #[stack(rename = "")]
+    Empty {
+        #[snafu(implicit)]
+        location: Location,
+    },
+    #[stack(rename = "A", rename = "B")]
+    Duplicate {
+        #[snafu(implicit)]
+        location: Location,
+    },
+}
+
+fn main() {}
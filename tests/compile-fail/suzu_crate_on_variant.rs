@@ -0,0 +1,10 @@
+// `crate` sets the path for the whole enum, so a variant-level override is rejected.
+use suzunari_error::*;
+
+#[suzunari_error]
+enum MyError {
+    #[suzu(crate = ::suzunari_error, display("failed"))]
+    Failed,
+}
+
+fn main() {}
@@ -0,0 +1,33 @@
+use suzunari_error::suzunari_error;
+
+#[suzunari_error]
+#[suzu(display("failed to open {pth}"))]
+struct OpenError {
+    path: String,
+}
+
+#[suzunari_error]
+enum QueryError {
+    #[suzu(display("query {id} timed out after {timeout_ms}ms"))]
+    Timeout { id: u64, timeout: u64 },
+}
+
+#[suzunari_error]
+#[suzu(display("write failed at {location}"))]
+struct WriteError {
+    #[suzu(location)]
+    at: suzunari_error::Location,
+}
+
+#[suzunari_error]
+#[suzu(display("inner"))]
+struct InnerError {}
+
+// skip_location injects no location field.
+#[suzunari_error]
+#[suzu(skip_location, display("outer at {location}"))]
+struct OuterError {
+    source: InnerError,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+// #[suzu(from(boxed))] expects the source error type, not BoxedStackError itself
+use suzunari_error::suzunari_error;
+
+#[suzunari_error]
+#[suzu(display("error"))]
+struct MyError {
+    #[suzu(from(boxed))]
+    source: suzunari_error::BoxedStackError,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use suzunari_error::suzunari_error;
+
+#[suzunari_error]
+#[snafu(display("error"))]
+struct MyError {
+    #[suzu(from)]
+    #[suzu(source(from(String, suzunari_error::DisplayError::new)))]
+    source: String,
+}
+
+fn main() {}
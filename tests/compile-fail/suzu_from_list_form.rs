@@ -1,4 +1,4 @@
-// #[suzu(from(...))] list form is rejected — from does not accept arguments
+// #[suzu(from(...))] list form is rejected unless the argument is `boxed`
 use suzunari_error::suzunari_error;
 
 #[suzunari_error]
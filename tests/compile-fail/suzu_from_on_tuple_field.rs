@@ -1,10 +1,8 @@
-// #[suzu(from)] on a tuple variant field is rejected
+// #[suzu(from)] on a tuple struct field is rejected
 use suzunari_error::suzunari_error;
 
 #[suzunari_error]
-enum MyError {
-    #[suzu(display("error"))]
-    Tuple(#[suzu(from)] String),
-}
+#[suzu(display("error"))]
+struct MyError(#[suzu(from)] String);
 
 fn main() {}
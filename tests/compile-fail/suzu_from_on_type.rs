@@ -0,0 +1,8 @@
+// `from` is field-only; applying it at the type level is rejected
+use suzunari_error::suzunari_error;
+
+#[suzunari_error]
+#[suzu(from, display("error"))]
+struct MyError {}
+
+fn main() {}
@@ -0,0 +1,24 @@
+// from(with = f) must name the source type: from(SourceType, with = f).
+use suzunari_error::*;
+
+#[derive(Debug)]
+struct Adapter;
+impl std::fmt::Display for Adapter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("adapter")
+    }
+}
+impl std::error::Error for Adapter {}
+
+fn to_adapter(_: i32) -> Adapter {
+    Adapter
+}
+
+#[suzunari_error]
+#[suzu(display("error"))]
+struct MyError {
+    #[suzu(from(with = to_adapter))]
+    source: Adapter,
+}
+
+fn main() {}
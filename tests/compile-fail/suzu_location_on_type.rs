@@ -0,0 +1,8 @@
+// `location` is field-only; applying it at the type level is rejected
+use suzunari_error::suzunari_error;
+
+#[suzunari_error]
+#[suzu(location, display("error"))]
+struct MyError {}
+
+fn main() {}
@@ -0,0 +1,12 @@
+// #[suzu(location)] on a field named `location` still requires a location type:
+// String is rejected with a single error on the field type.
+use suzunari_error::*;
+
+#[suzunari_error]
+#[suzu(display("error"))]
+struct MyError {
+    #[suzu(location)]
+    location: String,
+}
+
+fn main() {}
@@ -1,4 +1,4 @@
-// #[suzu(location)] requires the field type to be Location.
+// #[suzu(location)] on a type without AsLocation / GenerateImplicitData should fail.
 use suzunari_error::*;
 
 #[suzunari_error]
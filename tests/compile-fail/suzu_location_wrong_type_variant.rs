@@ -0,0 +1,16 @@
+// #[suzu(location)] on an enum variant field of a non-location type should fail
+// with a single error on the field type, as for structs.
+use suzunari_error::*;
+
+#[suzunari_error]
+enum MyError {
+    #[suzu(display("first"))]
+    First {
+        #[suzu(location)]
+        location: String,
+    },
+    #[suzu(display("second"))]
+    Second {},
+}
+
+fn main() {}
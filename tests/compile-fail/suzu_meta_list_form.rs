@@ -0,0 +1,11 @@
+// #[suzu(meta(...))] list form is rejected — meta does not accept arguments
+use suzunari_error::suzunari_error;
+
+#[suzunari_error]
+#[suzu(display("error"))]
+struct MyError {
+    #[suzu(meta(key))]
+    user_id: u32,
+}
+
+fn main() {}
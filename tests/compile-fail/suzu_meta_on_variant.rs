@@ -0,0 +1,9 @@
+use suzunari_error::suzunari_error;
+
+#[suzunari_error]
+enum MyError {
+    #[suzu(meta)]
+    Variant { user_id: u32 },
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+// `qualified` applies to the whole enum, not a single variant
+use suzunari_error::*;
+
+#[suzunari_error]
+enum MyError {
+    #[suzu(qualified, display("error"))]
+    Variant,
+}
+
+fn main() {}
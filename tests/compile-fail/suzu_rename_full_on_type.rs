@@ -0,0 +1,8 @@
+// `rename(full = ...)` only makes sense on enum variants
+use suzunari_error::*;
+
+#[suzunari_error]
+#[suzu(rename(full = "Other"), display("error"))]
+struct MyError {}
+
+fn main() {}
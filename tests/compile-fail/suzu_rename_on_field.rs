@@ -0,0 +1,11 @@
+// `rename` overrides type_name() and is only valid on types and variants
+use suzunari_error::*;
+
+#[suzunari_error]
+#[suzu(display("error"))]
+struct MyError {
+    #[suzu(rename = "Other")]
+    value: u32,
+}
+
+fn main() {}
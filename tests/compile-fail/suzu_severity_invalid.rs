@@ -0,0 +1,15 @@
+// `severity` takes one of the bare levels and only applies to types and variants
+use suzunari_error::*;
+
+#[suzunari_error]
+#[suzu(severity = fatal, display("error"))]
+struct UnknownLevelError {}
+
+#[suzunari_error]
+#[suzu(display("error"))]
+struct FieldLevelError {
+    #[suzu(severity = critical)]
+    value: u32,
+}
+
+fn main() {}
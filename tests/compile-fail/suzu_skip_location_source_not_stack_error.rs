@@ -0,0 +1,10 @@
+// The source of a `skip_location` item must be a `StackError` to supply the location.
+use suzunari_error::*;
+
+#[suzunari_error]
+#[suzu(skip_location, display("io failed"))]
+struct IoError {
+    source: std::io::Error,
+}
+
+fn main() {}
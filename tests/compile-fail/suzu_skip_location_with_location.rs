@@ -0,0 +1,16 @@
+// `skip_location` and an explicit location field contradict each other.
+use suzunari_error::*;
+
+#[suzunari_error]
+#[suzu(display("inner"))]
+struct InnerError {}
+
+#[suzunari_error]
+#[suzu(skip_location, display("outer"))]
+struct OuterError {
+    source: InnerError,
+    #[suzu(location)]
+    location: Location,
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+// `skip_location` takes the location from the source, so an item without one is rejected.
+use suzunari_error::*;
+
+#[suzunari_error]
+#[suzu(skip_location, display("failed"))]
+struct NoSourceError {
+    message: String,
+}
+
+#[suzunari_error]
+enum MyError {
+    #[suzu(skip_location)]
+    Unit,
+}
+
+fn main() {}
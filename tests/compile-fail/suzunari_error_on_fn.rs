@@ -0,0 +1,7 @@
+// #[suzunari_error] on a non-type item should be a clean parse error, not a panic.
+use suzunari_error::*;
+
+#[suzunari_error]
+fn not_an_error() {}
+
+fn main() {}
@@ -1,10 +1,10 @@
-// #[suzunari_error] requires named fields on enum variants
+// #[suzunari_error] only converts single-field tuple variants
 use suzunari_error::suzunari_error;
 
 #[suzunari_error]
 pub enum MyError {
     #[suzu(display("tuple"))]
-    Tuple(String),
+    Tuple(String, u32),
 }
 
 fn main() {}
@@ -561,3 +561,188 @@ fn test_all_unit_variant_enum() {
     assert_ne!(alpha.type_name(), beta.type_name());
     assert_ne!(beta.type_name(), gamma.type_name());
 }
+
+// --- find::<T>() ---
+
+fn level1_error() -> Level1Error {
+    let level3 = std::fs::read("/nonexistent")
+        .context(Level3Snafu)
+        .unwrap_err();
+    let level2 = Err::<(), _>(level3).context(Level2Snafu).unwrap_err();
+    Err::<(), _>(level2).context(Level1Snafu).unwrap_err()
+}
+
+#[test]
+fn test_find_middle_of_chain() {
+    let err = level1_error();
+    let middle = err
+        .find::<Level2Error>()
+        .expect("Level2Error is in the chain");
+    assert_eq!(middle.to_string(), "level 2");
+    assert!(err.find::<Level1Error>().is_some());
+    assert!(err.find::<Level3Error>().is_some());
+    assert!(err.find::<ErrorStruct>().is_none());
+}
+
+#[test]
+fn test_find_through_boxed_stack_error() {
+    let aggregate = Err::<(), _>(BoxedStackError::new(level1_error()))
+        .context(ErrorAggregateSnafu)
+        .unwrap_err();
+    assert!(aggregate.find::<Level1Error>().is_some());
+    assert!(aggregate.find::<Level2Error>().is_some());
+    assert!(aggregate.find::<BoxedStackError>().is_some());
+}
+
+// --- locations() ---
+
+#[test]
+fn test_locations_outermost_first() {
+    let err = level1_error();
+    let locations: Vec<Location> = err.locations().collect();
+
+    // Level1, Level2, Level3; the io::Error source has no location.
+    assert_eq!(locations.len(), 3);
+    assert!(locations.iter().all(|loc| loc.file() == file!()));
+    assert_eq!(locations[0], err.location());
+    // level1_error() builds the errors inner to outer, top to bottom.
+    assert!(locations[0].line() > locations[1].line());
+    assert!(locations[1].line() > locations[2].line());
+}
+
+// --- std errors converted into BoxedStackError ---
+
+fn read_config() -> Result<Vec<u8>, BoxedStackError> {
+    let bytes = std::fs::read("/nonexistent/config.toml")?;
+    Ok(bytes)
+}
+
+#[test]
+fn test_io_error_into_boxed_stack_error() {
+    let err = read_config().unwrap_err();
+    assert_eq!(err.type_name(), "io::Error");
+    assert_eq!(err.location().file(), file!());
+    // The `?` line in read_config().
+    assert_eq!(err.location().line(), line!() - 10);
+
+    let loc = err.location();
+    let io_message = std::fs::read("/nonexistent/config.toml")
+        .unwrap_err()
+        .to_string();
+    let report = format!("{}", StackReport::from_error(err));
+    assert_eq!(report, format!("Error: io::Error: {io_message}, at {loc}"));
+}
+
+#[test]
+fn test_parse_errors_into_boxed_stack_error() {
+    fn parse(raw: &[u8]) -> Result<u32, BoxedStackError> {
+        let text = std::str::from_utf8(raw)?;
+        Ok(text.parse::<u32>()?)
+    }
+
+    let utf8 = parse(&[0xff]).unwrap_err();
+    assert_eq!(utf8.type_name(), "Utf8Error");
+    let number = parse(b"x").unwrap_err();
+    assert_eq!(number.type_name(), "ParseIntError");
+    assert_eq!(number.location().line(), utf8.location().line() + 1);
+    assert_eq!(number.to_string(), "invalid digit found in string");
+    assert_eq!(number.depth(), 0);
+}
+
+// --- stack_context: located context for plain snafu errors ---
+
+#[derive(Debug, snafu::Snafu)]
+#[snafu(display("port {raw:?} is invalid"))]
+struct PlainPortError {
+    raw: String,
+    source: std::num::ParseIntError,
+}
+
+#[test]
+fn test_stack_context_locates_plain_error() {
+    let parse = |raw: &'static str| -> Result<u16, BoxedStackError> {
+        let port = raw.parse::<u16>().stack_context(PlainPortSnafu { raw })?;
+        Ok(port)
+    };
+    let line = line!() - 3;
+
+    assert_eq!(parse("8080").unwrap(), 8080);
+
+    let err = parse("http").unwrap_err();
+    assert_eq!(err.type_name(), "PlainPortError");
+    assert_eq!(err.location().file(), file!());
+    assert_eq!(err.location().line(), line);
+
+    let loc = err.location();
+    let report = format!("{}", StackReport::from_error(err));
+    assert_eq!(
+        report,
+        format!(
+            "Error: PlainPortError: port \"http\" is invalid, at {loc}\n\
+             Caused by (recent first):\n  \
+             1| invalid digit found in string"
+        )
+    );
+}
+
+#[suzunari_error]
+#[suzu(display("lease {id} expired"))]
+struct LeaseError {
+    id: u32,
+}
+
+#[suzunari_error]
+enum LeaseOpError {
+    #[suzu(display("renew failed"))]
+    Renew { source: LeaseError },
+    #[suzu(display("lease missing"))]
+    Missing {},
+}
+
+#[test]
+fn test_relocate_struct_overrides_location() {
+    // .build() captures a known location to compare against after relocating;
+    // .context() would capture it inside a helper.
+    let err = LeaseSnafu { id: 3u32 }.build();
+    let built = err.location();
+    let target: Location = core::panic::Location::caller();
+    let err = err.relocate(target);
+    assert_eq!(err.location(), target);
+    assert_ne!(err.location(), built);
+
+    let report = format!("{}", StackReport::from_error(err));
+    assert_eq!(
+        report,
+        format!("Error: LeaseError: lease 3 expired, at {target}")
+    );
+}
+
+#[test]
+fn test_relocate_enum_keeps_source_location() {
+    // .build() captures the source's location here, so it can be checked to
+    // survive relocating the outer error.
+    let inner = LeaseSnafu { id: 4u32 }.build();
+    let inner_loc = inner.location();
+    let err = Err::<(), _>(inner)
+        .context(RenewSnafu)
+        .unwrap_err()
+        .relocate_here();
+    let line = line!() - 1;
+    assert_eq!(err.location().file(), file!());
+    assert_eq!(err.location().line(), line);
+    match &err {
+        LeaseOpError::Renew { source, .. } => assert_eq!(source.location(), inner_loc),
+        LeaseOpError::Missing { .. } => unreachable!(),
+    }
+
+    let report = format!("{}", StackReport::from_error(err));
+    assert!(report.starts_with(&format!(
+        "Error: LeaseOpError::Renew: renew failed, at {}:{line}:",
+        file!()
+    )));
+
+    // .build() yields the unit variant directly; relocate_here() then moves it
+    // to this line, which is asserted.
+    let missing = MissingSnafu.build().relocate_here();
+    assert_eq!(missing.location().line(), line!() - 1);
+}
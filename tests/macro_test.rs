@@ -7,7 +7,9 @@
 // enabling exact location assertions. .context()/.fail() would capture the wrong line.
 
 use snafu::prelude::*;
-use suzunari_error::{Location, StackError, StackReport, suzunari_error};
+use suzunari_error::{
+    BoxedStackError, Location, StackError, StackReport, ensure_stack, suzunari_error,
+};
 
 // Test struct with #[suzunari_error] (auto-injects location)
 #[suzunari_error]
@@ -247,6 +249,48 @@ fn test_generic_with_where_clause() {
     assert_eq!(format!("{boxed}"), "where clause: 123");
 }
 
+// Generic enum through the raw derive, with a where clause and a generic source
+#[derive(Debug, Snafu, StackError)]
+enum RawGenericEnumError<T, S>
+where
+    T: core::fmt::Display + core::fmt::Debug + Send + Sync + 'static,
+    S: StackError + Send + Sync + 'static,
+{
+    #[snafu(display("value: {value}"))]
+    Value {
+        value: T,
+        #[snafu(implicit)]
+        location: Location,
+    },
+    #[snafu(display("wrapped"))]
+    Wrapped {
+        source: S,
+        #[snafu(implicit)]
+        location: Location,
+    },
+}
+
+#[test]
+fn test_raw_derive_generic_enum() {
+    let err: RawGenericEnumError<u8, TestError> = ValueSnafu { value: 7u8 }.build();
+    assert_eq!(err.type_name(), "RawGenericEnumError::Value");
+    assert!(err.location().file().ends_with("macro_test.rs"));
+    assert!(err.stack_source().is_none());
+
+    let inner = TestSnafu {
+        message: "inner".to_string(),
+    }
+    .build();
+    let err: RawGenericEnumError<u8, TestError> =
+        Err::<(), _>(inner).context(WrappedSnafu).unwrap_err();
+    assert_eq!(err.stack_source().unwrap().type_name(), "TestError");
+    assert_eq!(err.depth(), 1);
+
+    // From<RawGenericEnumError<T, S>> for BoxedStackError honors the where clause
+    let boxed: BoxedStackError = err.into();
+    assert_eq!(boxed.type_name(), "RawGenericEnumError::Wrapped");
+}
+
 // --- GAP-12: error types in nested modules ---
 
 mod nested {
@@ -262,6 +306,19 @@ mod nested {
         #[suzu(display("nested variant"))]
         Variant {},
     }
+
+    #[suzunari_error]
+    #[suzu(visibility(pub), qualified, display("qualified struct"))]
+    pub struct QualifiedError {}
+
+    #[suzunari_error]
+    #[suzu(visibility(pub), qualified)]
+    pub enum QualifiedEnum {
+        #[suzu(display("plain"))]
+        Plain,
+        #[suzu(rename = "Renamed", display("renamed"))]
+        Original,
+    }
 }
 
 #[test]
@@ -281,3 +338,196 @@ fn test_nested_module_errors() {
     let err = make_enum_error().unwrap_err();
     assert_eq!(err.type_name(), "NestedModEnum::Variant");
 }
+
+#[test]
+fn test_qualified_type_name() {
+    // Short names stay the default
+    assert_eq!(nested::NestedModSnafu.build().type_name(), "NestedModError");
+
+    assert_eq!(
+        nested::QualifiedSnafu.build().type_name(),
+        "macro_test::nested::QualifiedError"
+    );
+    assert_eq!(
+        nested::PlainSnafu.build().type_name(),
+        "macro_test::nested::QualifiedEnum::Plain"
+    );
+    // Combines with rename
+    assert_eq!(
+        nested::OriginalSnafu.build().type_name(),
+        "macro_test::nested::QualifiedEnum::Renamed"
+    );
+}
+
+#[test]
+fn test_module_path() {
+    let err = TestSnafu {
+        message: "top".to_string(),
+    }
+    .build();
+    assert_eq!(err.module_path(), Some("macro_test"));
+
+    let err = nested::NestedModSnafu.build();
+    assert_eq!(err.module_path(), Some("macro_test::nested"));
+
+    let err = nested::VariantSnafu.build();
+    assert_eq!(err.module_path(), Some("macro_test::nested"));
+    // Wrappers delegate to the inner error.
+    assert_eq!(
+        BoxedStackError::new(err).module_path(),
+        Some("macro_test::nested")
+    );
+}
+
+// Tuple struct with a positional location field (raw derive)
+#[derive(Debug, StackError)]
+struct TupleError(u32, Location);
+
+impl core::fmt::Display for TupleError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "code {}", self.0)
+    }
+}
+
+impl core::error::Error for TupleError {}
+
+#[test]
+fn test_tuple_struct_derive() {
+    let error = TupleError(7, core::panic::Location::caller());
+
+    let file = file!();
+    let line = line!() - 3;
+    assert_eq!(error.type_name(), "TupleError");
+    assert_eq!(error.location().line(), line);
+    assert_eq!(
+        format!("{:?}", StackReport::from(error)),
+        format!("Error: TupleError: code 7, at {file}:{line}:31")
+    );
+}
+
+// Unit struct: #[suzunari_error] turns it into a struct with an injected location
+#[suzunari_error]
+#[suzu(display("timed out"))]
+struct TimeoutError;
+
+#[test]
+fn test_unit_struct() {
+    let error = TimeoutSnafu.build();
+
+    let file = file!();
+    let line = line!() - 3;
+    assert_eq!(error.type_name(), "TimeoutError");
+    assert_eq!(
+        format!("{:?}", StackReport::from(error)),
+        format!("Error: TimeoutError: timed out, at {file}:{line}:30")
+    );
+}
+
+// Enum mixing unit and named-field variants; #[suzunari_error] injects a
+// location into each, including the unit variant.
+#[suzunari_error]
+enum MixedVariantError {
+    #[suzu(display("closed"))]
+    Closed,
+    #[suzu(display("bad code {code}"))]
+    BadCode { code: u32 },
+}
+
+#[test]
+fn test_mixed_unit_and_named_variants() {
+    let closed = ClosedSnafu.build();
+    let closed_line = line!() - 1;
+    let bad_code = BadCodeSnafu { code: 3u32 }.build();
+    let bad_code_line = line!() - 1;
+
+    assert_eq!(closed.type_name(), "MixedVariantError::Closed");
+    assert_eq!(closed.location().line(), closed_line);
+    assert_eq!(closed.to_string(), "closed");
+    assert_eq!(bad_code.type_name(), "MixedVariantError::BadCode");
+    assert_eq!(bad_code.location().line(), bad_code_line);
+    assert_eq!(bad_code.to_string(), "bad code 3");
+}
+
+// --- ensure_stack! ---
+
+#[suzunari_error]
+enum EnsureError {
+    #[suzu(display("value {value} is too large"))]
+    TooLarge { value: u32 },
+    #[suzu(display("limit {limit} exceeded by {excess}"))]
+    Exceeded { limit: u32, excess: u32 },
+    #[suzu(display("empty input"))]
+    Empty,
+}
+
+#[suzunari_error]
+#[suzu(display("negative"))]
+struct NegativeError {}
+
+fn check_value(value: u32) -> Result<u32, EnsureError> {
+    ensure_stack!(value != 0, EnsureError::Empty);
+    ensure_stack!(value < 100, EnsureError::TooLarge { value });
+    ensure_stack!(
+        value < 50,
+        EnsureError::Exceeded {
+            limit: 50,
+            excess: value - 50,
+        },
+    );
+    Ok(value)
+}
+
+#[test]
+fn test_ensure_stack_passes_when_condition_holds() {
+    assert_eq!(check_value(10).unwrap(), 10);
+}
+
+#[test]
+fn test_ensure_stack_returns_early_with_location() {
+    let err = check_value(0).unwrap_err();
+    assert!(matches!(err, EnsureError::Empty { .. }));
+
+    let err = check_value(150).unwrap_err();
+    assert_eq!(err.to_string(), "value 150 is too large");
+    let ensure_line = line!() - 23;
+    assert_eq!(err.location().file(), file!());
+    assert_eq!(err.location().line(), ensure_line);
+    assert_eq!(err.location().column(), 5);
+
+    let err = check_value(70).unwrap_err();
+    assert_eq!(err.to_string(), "limit 50 exceeded by 20");
+}
+
+#[test]
+fn test_ensure_stack_converts_with_into() {
+    fn check(value: i32) -> Result<(), BoxedStackError> {
+        ensure_stack!(value >= 0, NegativeError);
+        Ok(())
+    }
+    assert!(check(1).is_ok());
+    assert_eq!(check(-1).unwrap_err().type_name(), "NegativeError");
+}
+
+// `#[stack(rename ...)]` is what `#[suzu(rename ...)]` lowers to
+#[derive(Debug, Snafu, StackError)]
+enum DerivedRenameError {
+    #[stack(rename = "Renamed")]
+    Plain {
+        #[snafu(implicit)]
+        location: Location,
+    },
+    #[stack(rename(full = "Standalone"))]
+    Full {
+        #[snafu(implicit)]
+        location: Location,
+    },
+}
+
+#[test]
+fn test_stack_rename_with_derive() {
+    assert_eq!(
+        PlainSnafu.build().type_name(),
+        "DerivedRenameError::Renamed"
+    );
+    assert_eq!(FullSnafu.build().type_name(), "Standalone");
+}
@@ -0,0 +1,93 @@
+#![cfg(feature = "std")]
+//! Uses only `suzunari_error::prelude`, to check that it covers the core API.
+
+use suzunari_error::prelude::*;
+
+#[suzunari_error]
+#[suzu(display("lookup of {key} failed"))]
+struct KeyLookupError {
+    key: &'static str,
+    source: std::io::Error,
+}
+
+#[suzunari_error]
+enum ServiceError {
+    #[suzu(display("lookup failed"))]
+    Lookup {
+        #[suzu(from(boxed))]
+        source: KeyLookupError,
+    },
+    #[suzu(display("limit {limit} exceeded"))]
+    Limit { limit: u32 },
+}
+
+#[derive(Debug)]
+struct Opaque;
+
+impl std::fmt::Display for Opaque {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("opaque")
+    }
+}
+
+fn lookup(key: &'static str) -> Result<(), KeyLookupError> {
+    std::fs::read("/nonexistent/prelude").context(KeyLookupSnafu { key })?;
+    Ok(())
+}
+
+fn serve(limit: u32) -> Result<(), ServiceError> {
+    ensure!(limit < 10, LimitSnafu { limit });
+    lookup("user").context(LookupSnafu)?;
+    Ok(())
+}
+
+#[test]
+fn test_prelude_covers_core_api() {
+    let err = serve(1).unwrap_err();
+    assert_eq!(err.type_name(), "ServiceError::Lookup");
+    let location: Location = err.location();
+    assert_eq!(location.file(), file!());
+    assert_eq!(err.severity(), Severity::Error);
+
+    let report = StackReport::from_error(err).to_string();
+    assert!(report.contains("1| KeyLookupError: lookup of user failed, at "));
+
+    let limit = BoxedStackError::new(serve(10).unwrap_err());
+    assert_eq!(limit.to_string(), "limit 10 exceeded");
+    let shared = SharedStackError::from(limit);
+    assert_eq!(shared.clone().type_name(), "ServiceError::Limit");
+
+    let wrapped = DisplayError::new(Opaque);
+    assert_eq!(wrapped.to_string(), "opaque");
+    assert_eq!(
+        None::<u8>
+            .context(LimitSnafu { limit: 1u32 })
+            .unwrap_err()
+            .depth(),
+        0
+    );
+}
+
+#[test]
+fn test_prelude_macros() {
+    fn check(value: u32) -> Result<u32, BoxedStackError> {
+        if value == 0 {
+            return Err(stack_error!("value must be positive"));
+        }
+        let parsed = whatever_stack!(value.to_string().parse::<u8>(), "{value} is too big");
+        Ok(u32::from(parsed))
+    }
+    assert_eq!(check(0).unwrap_err().type_name(), "Message");
+    assert_eq!(check(300).unwrap_err().type_name(), "Whatever");
+    assert_eq!(check(3).unwrap(), 3);
+
+    fn guarded(limit: u32) -> Result<(), ServiceError> {
+        ensure_stack!(limit < 10, ServiceError::Limit { limit });
+        Ok(())
+    }
+    assert!(guarded(11).is_err());
+
+    let whatever: Result<(), SuzunariWhatever> =
+        "x".parse::<u8>().map(drop).whatever_context("not a number");
+    assert_eq!(whatever.unwrap_err().to_string(), "not a number");
+}
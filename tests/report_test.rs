@@ -60,6 +60,171 @@ fn test_report_from_result_err() {
     assert!(output.contains("test error: direct"));
 }
 
+// #[report] keeps parameters, including owned, `mut`, and destructured ones;
+// the function is called normally and returns the report.
+struct RunConfig {
+    name: String,
+    retries: u32,
+}
+
+#[suzunari_error::report]
+fn run_with_config(
+    config: RunConfig,
+    mut attempts: u32,
+    (min, max): (u32, u32),
+) -> Result<(), TestReportError> {
+    attempts += config.retries;
+    let name = config.name; // moves out of the parameter
+    ensure!(
+        (min..=max).contains(&attempts),
+        TestReportSnafu {
+            message: format!("{name}: {attempts} attempts"),
+        }
+    );
+    Ok(())
+}
+
+#[test]
+fn test_report_with_parameters() {
+    let config = || RunConfig {
+        name: "job".to_string(),
+        retries: 2,
+    };
+    assert!(run_with_config(config(), 1, (0, 5)).into_result().is_ok());
+
+    let report = run_with_config(config(), 9, (0, 5));
+    let output = format!("{report}");
+    assert!(
+        output.starts_with("Error: TestReportError: test error: job: 11 attempts, at "),
+        "{output}"
+    );
+}
+
+// #[report] accepts boxed trait objects, which implement StackError.
+
+#[suzunari_error::report]
+fn boxed_failure(fail: bool) -> Result<(), Box<dyn StackError + Send + Sync>> {
+    if fail {
+        return TestReportSnafu { message: "boxed" }
+            .fail()
+            .map_err(|err| Box::new(err) as Box<dyn StackError + Send + Sync>);
+    }
+    Ok(())
+}
+
+#[suzunari_error::report]
+fn local_boxed_failure() -> Result<(), Box<dyn StackError>> {
+    TestReportSnafu { message: "local" }
+        .fail()
+        .map_err(|err| Box::new(err) as Box<dyn StackError>)
+}
+
+#[test]
+fn test_report_boxed_trait_object() {
+    assert!(boxed_failure(false).into_result().is_ok());
+
+    let output = format!("{}", boxed_failure(true));
+    assert!(
+        output.starts_with("Error: TestReportError: test error: boxed, at "),
+        "{output}"
+    );
+    let output = format!("{}", local_boxed_failure());
+    assert!(
+        output.starts_with("Error: TestReportError: test error: local, at "),
+        "{output}"
+    );
+}
+
+// #[report(keep_inner = ...)] keeps the original body as a private fn
+
+#[suzunari_error::report(keep_inner = check_range_inner)]
+fn check_range(mut value: u32, (min, max): (u32, u32)) -> Result<(), TestReportError> {
+    value += 1;
+    ensure!(
+        (min..=max).contains(&value),
+        TestReportSnafu {
+            message: format!("{value} out of range"),
+        }
+    );
+    Ok(())
+}
+
+// Lint attributes below #[report] still cover the body in the inner fn.
+#[suzunari_error::report(keep_inner = checked_inner)]
+#[expect(unused_variables)]
+#[allow(clippy::int_plus_one)]
+fn checked(value: u32) -> Result<(), TestReportError> {
+    let unused = value;
+    ensure!(
+        value + 1 <= 3,
+        TestReportSnafu {
+            message: "too large"
+        }
+    );
+    Ok(())
+}
+
+struct Job {
+    limit: u32,
+}
+
+impl Job {
+    #[suzunari_error::report(keep_inner = run_inner)]
+    fn run(&self, attempts: u32) -> Result<(), TestReportError> {
+        ensure!(
+            attempts <= self.limit,
+            TestReportSnafu {
+                message: format!("{attempts} attempts"),
+            }
+        );
+        Ok(())
+    }
+}
+
+impl Job {
+    // Receiver-less associated fns name the inner fn through `Self::`.
+    #[suzunari_error::report(keep_inner = Self::validate_inner)]
+    fn validate(limit: u32) -> Result<(), TestReportError> {
+        ensure!(
+            limit > 0,
+            TestReportSnafu {
+                message: "zero limit"
+            }
+        );
+        Ok(())
+    }
+}
+
+#[test]
+fn test_report_keep_inner() {
+    assert!(check_range_inner(1, (0, 5)).is_ok());
+    let err = check_range_inner(9, (0, 5)).unwrap_err();
+    assert_eq!(err.to_string(), "test error: 10 out of range");
+
+    assert!(check_range(1, (0, 5)).into_result().is_ok());
+    let output = check_range(9, (0, 5)).to_string();
+    assert!(
+        output.starts_with("Error: TestReportError: test error: 10 out of range, at "),
+        "{output}"
+    );
+
+    assert!(checked_inner(2).is_ok());
+    assert!(checked(3).to_string().contains("test error: too large"));
+
+    let job = Job { limit: 2 };
+    assert!(job.run_inner(2).is_ok());
+    assert!(job.run_inner(3).is_err());
+    assert!(job.run(3).to_string().contains("test error: 3 attempts"));
+
+    assert!(Job::validate_inner(1).is_ok());
+    assert!(Job::validate_inner(0).is_err());
+    assert!(
+        Job::validate(0)
+            .to_string()
+            .contains("test error: zero limit")
+    );
+}
+
 // #[report] with ? operator — verifies error propagation works through the closure wrapper
 #[suzunari_error]
 #[suzu(display("io wrapper"))]
@@ -82,6 +247,44 @@ fn test_report_with_question_mark_propagation() {
     assert!(output.contains("Caused by"));
 }
 
+#[test]
+fn test_find_source_reaches_io_error() {
+    let err = std::fs::read("this_file_does_not_exist_for_test")
+        .context(IoWrapperSnafu)
+        .unwrap_err();
+    let io = err
+        .find_source::<std::io::Error>()
+        .expect("io::Error is the wrapper's source");
+    assert_eq!(io.kind(), std::io::ErrorKind::NotFound);
+    assert!(err.find_source::<IoWrapperError>().is_some());
+    assert!(err.find_source::<std::fmt::Error>().is_none());
+}
+
+#[test]
+fn test_source_is_io_error() {
+    let err = std::fs::read("this_file_does_not_exist_for_test")
+        .context(IoWrapperSnafu)
+        .unwrap_err();
+    assert!(err.source_is::<std::io::Error>());
+    assert!(err.source_is::<IoWrapperError>());
+    assert!(!err.source_is::<std::fmt::Error>());
+    assert!(!err.source_is::<TestReportError>());
+}
+
+#[test]
+fn test_report_is_error_with_inner_source() {
+    fn boxed(report: StackReport<TestReportError>) -> Box<dyn std::error::Error> {
+        Box::new(report)
+    }
+    let report = boxed(failure_case());
+    let source = report.source().unwrap();
+    let inner = source.downcast_ref::<TestReportError>().unwrap();
+    assert_eq!(inner.message, "boom");
+    assert!(report.to_string().starts_with("Error: TestReportError: "));
+
+    assert!(boxed(success_case()).source().is_none());
+}
+
 #[test]
 fn test_report_termination_success() {
     use std::process::{ExitCode, Termination};
@@ -96,3 +299,1141 @@ fn test_report_termination_failure() {
     // Writes to stderr and returns FAILURE
     assert_eq!(report.report(), ExitCode::FAILURE);
 }
+
+// --- associated functions ---
+// The trait must declare the transformed return type (`StackReport<E>`),
+// because #[report] rewrites the signature of the impl item.
+
+trait Task {
+    type Error: StackError;
+    fn run() -> StackReport<Self::Error>;
+}
+
+struct FailingTask;
+
+impl Task for FailingTask {
+    type Error = TestReportError;
+
+    #[suzunari_error::report]
+    fn run() -> Result<(), Self::Error> {
+        ensure!(false, TestReportSnafu { message: "task" });
+        Ok(())
+    }
+}
+
+struct Runner;
+
+impl Runner {
+    #[suzunari_error::report]
+    fn run(succeed: bool) -> Result<(), TestReportError> {
+        ensure!(succeed, TestReportSnafu { message: "runner" });
+        Ok(())
+    }
+}
+
+#[test]
+fn test_report_on_trait_impl_associated_fn() {
+    let output = format!("{}", FailingTask::run());
+    assert!(output.starts_with("Error: TestReportError: test error: task, at "));
+}
+
+#[test]
+fn test_report_on_inherent_associated_fn() {
+    assert_eq!(format!("{}", Runner::run(true)), "");
+    let output = format!("{}", Runner::run(false));
+    assert!(output.starts_with("Error: TestReportError: test error: runner, at "));
+}
+
+// --- into_boxed_error ---
+
+#[test]
+fn test_report_into_boxed_error_failure() {
+    let (err, loc) = error_at_known_location();
+    let boxed = StackReport::from_error(err)
+        .into_boxed_error()
+        .expect("failure report should yield an error");
+    assert_eq!(boxed.type_name(), "TestReportError");
+    assert_eq!(boxed.location(), loc);
+    assert_eq!(boxed.to_string(), "test error: linked");
+}
+
+#[test]
+fn test_report_into_boxed_error_success() {
+    assert!(success_case().into_boxed_error().is_none());
+}
+
+// --- max_depth ---
+
+#[suzunari_error]
+#[suzu(display("leaf"))]
+struct DepthLeafError {}
+
+#[suzunari_error]
+#[suzu(display("level {level}"))]
+struct DepthLevelError {
+    level: usize,
+    source: BoxedStackError,
+}
+
+/// Builds a chain whose top-level error has `depth() == levels`.
+fn nested_error(levels: usize) -> Result<(), BoxedStackError> {
+    if levels == 0 {
+        ensure!(false, DepthLeafSnafu);
+        return Ok(());
+    }
+    nested_error(levels - 1).context(DepthLevelSnafu { level: levels })?;
+    Ok(())
+}
+
+#[test]
+fn test_report_max_depth_elides_remaining_causes() {
+    let err = nested_error(5).unwrap_err();
+    assert_eq!(err.depth(), 5);
+
+    let output = format!("{}", StackReport::from_error(err).max_depth(2));
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(lines.len(), 5, "unexpected output:\n{output}");
+    assert!(lines[0].starts_with("Error: DepthLevelError: level 5, at "));
+    assert_eq!(lines[1], "Caused by (recent first):");
+    assert!(lines[2].starts_with("  1| DepthLevelError: level 4, at "));
+    assert!(lines[3].starts_with("  2| DepthLevelError: level 3, at "));
+    assert_eq!(lines[4], "  ... (3 more)");
+}
+
+#[test]
+fn test_report_max_depth_zero_prints_top_level_only() {
+    let err = nested_error(5).unwrap_err();
+    let output = format!("{}", StackReport::from_error(err).max_depth(0));
+    assert!(output.starts_with("Error: DepthLevelError: level 5, at "));
+    assert!(!output.contains('\n'), "unexpected output:\n{output}");
+}
+
+#[test]
+fn test_report_max_depth_not_reached_prints_full_chain() {
+    let err = nested_error(2).unwrap_err();
+    let output = format!("{}", StackReport::from_error(err).max_depth(2));
+    assert!(output.contains("2| DepthLeafError: leaf, at "));
+    assert!(!output.contains("more)"));
+}
+
+// --- links ---
+
+// Re-renders the default layout from `links()` alone.
+fn render_from_links<E: StackError>(report: &StackReport<E>) -> String {
+    let mut out = String::new();
+    for (index, link) in report.links().enumerate() {
+        let line = match link {
+            Link::StackFrame {
+                type_name,
+                location,
+                error,
+            } => format!("{type_name}: {error}, at {location}"),
+            Link::PlainFrame { error } => error.to_string(),
+            _ => unreachable!(),
+        };
+        match index {
+            0 => out.push_str(&format!("Error: {line}")),
+            1 => out.push_str(&format!("\nCaused by (recent first):\n  1| {line}")),
+            _ => out.push_str(&format!("\n  {index}| {line}")),
+        }
+    }
+    out
+}
+
+#[test]
+fn test_links_reconstruct_report() {
+    let report = StackReport::from_error(nested_error(3).unwrap_err());
+    assert_eq!(report.links().count(), report.frame_count());
+    assert_eq!(render_from_links(&report), report.to_string());
+
+    let err = std::fs::read("this_file_does_not_exist_for_test")
+        .context(IoWrapperSnafu)
+        .unwrap_err();
+    let report = StackReport::from_error(err);
+    let links: Vec<Link<'_>> = report.links().collect();
+    assert!(matches!(links[0], Link::StackFrame { .. }));
+    assert!(matches!(links[1], Link::PlainFrame { .. }));
+    assert_eq!(render_from_links(&report), report.to_string());
+}
+
+#[test]
+fn test_links_empty_for_ok() {
+    let report: StackReport<TestReportError> = StackReport::from(Ok(()));
+    assert!(report.links().next().is_none());
+}
+
+// --- transparent frames ---
+
+#[suzunari_error]
+#[suzu(display("disk full"))]
+struct DiskFullError {
+    source: std::io::Error,
+}
+
+#[suzunari_error]
+enum StoreError {
+    #[suzu(transparent)]
+    Disk { source: DiskFullError },
+    #[suzu(display("store closed"))]
+    Closed,
+}
+
+#[suzunari_error]
+#[suzu(display("save failed"))]
+struct SaveError {
+    source: StoreError,
+}
+
+fn save_error() -> SaveError {
+    fn disk() -> Result<(), DiskFullError> {
+        Err(std::io::Error::other("no space left")).context(DiskFullSnafu)
+    }
+    fn store() -> Result<(), StoreError> {
+        disk()?;
+        Ok(())
+    }
+    store().context(SaveSnafu).unwrap_err()
+}
+
+#[test]
+fn test_report_skips_transparent_frames() {
+    let err = save_error();
+    let store = err.stack_source().unwrap();
+    assert!(store.is_transparent());
+    let closed = ClosedSnafu.fail::<()>().unwrap_err();
+    assert!(!closed.is_transparent());
+
+    let report = StackReport::from_error(err);
+    let output = report.to_string();
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(lines.len(), 4, "unexpected output:\n{output}");
+    assert!(lines[0].starts_with("Error: SaveError: save failed, at "));
+    assert!(lines[2].starts_with("  1| DiskFullError: disk full, at "));
+    assert_eq!(lines[3], "  2| no space left");
+    assert!(!output.contains("StoreError"));
+
+    // links() still yields the transparent frame.
+    assert_eq!(report.links().filter(Link::is_transparent).count(), 1);
+}
+
+#[test]
+fn test_report_transparent_frames_with_max_depth() {
+    let output = StackReport::from_error(save_error())
+        .max_depth(1)
+        .to_string();
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(lines.len(), 4, "unexpected output:\n{output}");
+    assert!(lines[2].starts_with("  1| DiskFullError: disk full, at "));
+    assert_eq!(lines[3], "  ... (1 more)");
+}
+
+// --- alternate tree layout ---
+
+#[test]
+fn test_report_alternate_renders_tree() {
+    let err = nested_error(2).unwrap_err();
+    let report = StackReport::from_error(err);
+
+    let tree = format!("{report:#}");
+    let lines: Vec<&str> = tree.lines().collect();
+    assert_eq!(lines.len(), 3, "unexpected output:\n{tree}");
+    assert!(lines[0].starts_with("Error: DepthLevelError: level 2, at "));
+    assert!(lines[1].starts_with("╰─▶ DepthLevelError: level 1, at "));
+    assert!(lines[2].starts_with("    ╰─▶ DepthLeafError: leaf, at "));
+    assert!(!tree.contains("Caused by"));
+
+    let plain = format!("{report}");
+    assert!(!plain.contains("╰─▶"), "unexpected output:\n{plain}");
+    assert!(plain.contains("Caused by (recent first):"));
+}
+
+#[test]
+fn test_report_alternate_tree_with_max_depth() {
+    let err = nested_error(5).unwrap_err();
+    let tree = format!("{:#}", StackReport::from_error(err).max_depth(1));
+    let lines: Vec<&str> = tree.lines().collect();
+    assert_eq!(lines.len(), 3, "unexpected output:\n{tree}");
+    assert!(lines[1].starts_with("╰─▶ DepthLevelError: level 4, at "));
+    assert_eq!(lines[2], "    ╰─▶ ... (4 more)");
+}
+
+// --- location_uri ---
+
+fn error_at_known_location() -> (TestReportError, Location) {
+    let location = core::panic::Location::caller();
+    let err = TestReportError {
+        message: "linked".to_string(),
+        location,
+    };
+    (err, location)
+}
+
+fn style_with(scheme: LocationUriScheme) -> StackReportStyle {
+    let mut style = StackReportStyle::default();
+    style.location_uri = Some(scheme);
+    style
+}
+
+#[test]
+fn test_report_location_uri_vscode() {
+    let (err, loc) = error_at_known_location();
+    let style = style_with(LocationUriScheme::VsCode {
+        base_dir: "/work/repo".into(),
+    });
+    let output = format!("{}", StackReport::from_error(err).style(style));
+    assert_eq!(
+        output,
+        format!(
+            "Error: TestReportError: test error: linked, at vscode://file/work/repo/{}:{}:{}",
+            loc.file(),
+            loc.line(),
+            loc.column()
+        )
+    );
+}
+
+#[test]
+fn test_report_location_uri_osc8() {
+    let (err, loc) = error_at_known_location();
+    let style = style_with(LocationUriScheme::Osc8 {
+        base_dir: "/work/repo".into(),
+    });
+    let output = format!("{}", StackReport::from_error(err).style(style));
+    assert_eq!(
+        output,
+        format!(
+            "Error: TestReportError: test error: linked, at \
+             \x1b]8;;file:///work/repo/{}\x1b\\{loc}\x1b]8;;\x1b\\",
+            loc.file()
+        )
+    );
+}
+
+#[test]
+fn test_location_uri_template_url() {
+    let (err, loc) = error_at_known_location();
+    let scheme = LocationUriScheme::Template {
+        base_dir: "/work/repo".into(),
+        template: "zed://file{path}:{line}:{column}?{unknown}".into(),
+    };
+    let expected = format!(
+        "zed://file/work/repo/{}:{}:{}?{{unknown}}",
+        loc.file(),
+        loc.line(),
+        loc.column()
+    );
+    assert_eq!(scheme.url(loc), expected);
+
+    let output = format!("{}", StackReport::from_error(err).style(style_with(scheme)));
+    assert!(output.ends_with(&format!(", at {expected}")), "{output}");
+
+    let osc8 = LocationUriScheme::Osc8 {
+        base_dir: "/work/repo".into(),
+    };
+    assert_eq!(osc8.url(loc), format!("file:///work/repo/{}", loc.file()));
+}
+
+#[test]
+fn test_report_default_style_prints_plain_location() {
+    let (err, loc) = error_at_known_location();
+    let output = format!(
+        "{}",
+        StackReport::from_error(err).style(StackReportStyle::default())
+    );
+    assert!(output.ends_with(&format!(", at {loc}")));
+}
+
+// --- hide_paths ---
+
+#[test]
+fn test_report_hide_paths_prints_basename_only() {
+    let (err, loc) = error_at_known_location();
+    let mut style = StackReportStyle::default();
+    style.hide_paths = true;
+    let output = format!("{}", StackReport::from_error(err).style(style));
+    assert_eq!(
+        output,
+        format!(
+            "Error: TestReportError: test error: linked, at report_test.rs:{}:{}",
+            loc.line(),
+            loc.column()
+        )
+    );
+    assert!(!output.contains("tests/"));
+}
+
+#[test]
+fn test_report_hide_paths_overrides_location_uri() {
+    let (err, loc) = error_at_known_location();
+    let mut style = style_with(LocationUriScheme::VsCode {
+        base_dir: "/work/repo".into(),
+    });
+    style.hide_paths = true;
+    let output = format!("{}", StackReport::from_error(err).style(style));
+    assert!(output.ends_with(&format!(
+        ", at report_test.rs:{}:{}",
+        loc.line(),
+        loc.column()
+    )));
+}
+
+// --- show_module_path ---
+
+#[test]
+fn test_report_show_module_path() {
+    let err = nested_error(1).unwrap_err();
+    let mut style = StackReportStyle::default();
+    style.show_module_path = true;
+    let output = format!("{}", StackReport::from_error(err).style(style));
+    let lines: Vec<&str> = output.lines().collect();
+    assert!(lines[0].ends_with(" in report_test"), "{output}");
+    assert!(lines[2].ends_with(" in report_test"), "{output}");
+}
+
+// --- map_locations ---
+
+#[test]
+fn test_report_map_locations_strips_prefix_from_all_frames() {
+    let err = nested_error(2).unwrap_err();
+    assert!(err.location().file().starts_with("tests/"));
+
+    let output = format!(
+        "{}",
+        StackReport::from_error(err).map_locations(|loc| {
+            let file = loc.file().strip_prefix("tests/").unwrap_or(loc.file());
+            format!("{file}:{}", loc.line())
+        })
+    );
+    let frames: Vec<&str> = output.lines().filter(|l| l.contains(", at ")).collect();
+    assert_eq!(frames.len(), 3);
+    for frame in frames {
+        assert!(frame.contains(", at report_test.rs:"), "{frame}");
+    }
+    assert!(!output.contains("tests/"));
+}
+
+#[test]
+fn test_report_map_locations_overrides_style() {
+    let (err, loc) = error_at_known_location();
+    let mut style = StackReportStyle::default();
+    style.hide_paths = true;
+    let output = format!(
+        "{}",
+        StackReport::from_error(err)
+            .style(style)
+            .map_locations(|loc| format!("<{}>", loc.line()))
+    );
+    assert!(output.ends_with(&format!(", at <{}>", loc.line())));
+}
+
+// --- strip_prefix ---
+
+#[test]
+fn test_report_strip_prefix_trims_rendered_paths_only() {
+    let (err, loc) = error_at_known_location();
+    assert!(loc.file().starts_with("tests/"));
+
+    let report = StackReport::from_error(err).strip_prefix("tests/");
+    let output = format!("{report}");
+    assert!(
+        output.ends_with(&format!(
+            ", at report_test.rs:{}:{}",
+            loc.line(),
+            loc.column()
+        )),
+        "{output}"
+    );
+    assert!(!output.contains("tests/"));
+
+    let err = report.err().unwrap();
+    assert_eq!(err.location(), loc);
+    assert_eq!(err.location().file(), loc.file());
+}
+
+#[test]
+fn test_report_strip_prefix_matches_whole_components() {
+    let (err, loc) = error_at_known_location();
+    let output = format!("{}", StackReport::from_error(err).strip_prefix("test"));
+    assert!(output.ends_with(&format!(", at {loc}")), "{output}");
+
+    let err = nested_error(2).unwrap_err();
+    let output = format!("{}", StackReport::from_error(err).strip_prefix("tests"));
+    let frames: Vec<&str> = output.lines().filter(|l| l.contains(", at ")).collect();
+    assert_eq!(frames.len(), 3);
+    for frame in frames {
+        assert!(frame.contains(", at report_test.rs:"), "{frame}");
+    }
+}
+
+// --- exact output format ---
+
+// Pins the full report layout (header, numbering, recent-first order, no
+// trailing newline) so changes to the format are caught explicitly.
+#[test]
+fn test_report_exact_format() {
+    let err = nested_error(2).unwrap_err();
+    let top = err.location();
+    let middle = err.stack_source().unwrap();
+    let leaf = middle.stack_source().unwrap();
+    let (middle, leaf) = (middle.location(), leaf.location());
+
+    assert_eq!(
+        format!("{}", StackReport::from_error(err)),
+        format!(
+            "Error: DepthLevelError: level 2, at {top}\n\
+             Caused by (recent first):\n  \
+             1| DepthLevelError: level 1, at {middle}\n  \
+             2| DepthLeafError: leaf, at {leaf}"
+        )
+    );
+}
+
+// --- into_result ---
+
+#[test]
+fn test_report_into_result_round_trip() {
+    let ok: Result<(), TestReportError> = Ok(());
+    assert!(StackReport::from(ok).into_result().is_ok());
+
+    let (err, loc) = error_at_known_location();
+    let result = StackReport::from(Err(err)).max_depth(0).into_result();
+    let err = result.unwrap_err();
+    assert_eq!(err.message, "linked");
+    assert_eq!(err.location(), loc);
+}
+
+// --- as_result / err ---
+
+#[test]
+fn test_report_as_result_and_err() {
+    let ok: Result<(), TestReportError> = Ok(());
+    let report = StackReport::from(ok);
+    assert!(report.as_result().is_ok());
+    assert!(report.err().is_none());
+
+    let (err, loc) = error_at_known_location();
+    let report = StackReport::from_error(err);
+    let borrowed = report.as_result().unwrap_err();
+    assert_eq!(borrowed.message, "linked");
+    assert_eq!(borrowed.location(), loc);
+    // The report is still usable after borrowing
+    assert!(format!("{report}").starts_with("Error: TestReportError: "));
+
+    let err = report.err().unwrap();
+    assert_eq!(err.type_name(), "TestReportError");
+    assert_eq!(err.location(), loc);
+}
+
+// --- header_label / causes_label ---
+
+#[test]
+fn test_report_custom_labels() {
+    let err = nested_error(1).unwrap_err();
+    let top = err.location();
+    let leaf = err.stack_source().unwrap().location();
+
+    let report = StackReport::from_error(err)
+        .header_label("Fehler")
+        .causes_label("Verursacht durch");
+    assert_eq!(
+        format!("{report}"),
+        format!(
+            "Fehler: DepthLevelError: level 1, at {top}\n\
+             Verursacht durch:\n  \
+             1| DepthLeafError: leaf, at {leaf}"
+        )
+    );
+    assert!(!format!("{report:#}").contains("Verursacht durch"));
+}
+
+// --- with_root_debug ---
+
+#[test]
+fn test_report_with_root_debug() {
+    let report = |root_debug: bool| {
+        let err = std::fs::read("this_file_does_not_exist_for_test")
+            .context(IoWrapperSnafu)
+            .unwrap_err();
+        let report = StackReport::from_error(err);
+        if root_debug {
+            report.with_root_debug().to_string()
+        } else {
+            report.to_string()
+        }
+    };
+    let plain = report(false);
+    assert!(!plain.contains("Root cause (debug)"));
+
+    let io_debug = format!(
+        "{:?}",
+        std::fs::read("this_file_does_not_exist_for_test").unwrap_err()
+    );
+    let with_debug = report(true);
+    assert_eq!(
+        with_debug,
+        format!("{plain}\nRoot cause (debug): {io_debug}")
+    );
+    assert!(
+        StackReport::from(success_case().into_result())
+            .with_root_debug()
+            .to_string()
+            .is_empty()
+    );
+}
+
+// --- index_style ---
+
+#[test]
+fn test_report_index_style() {
+    let err = nested_error(2).unwrap_err();
+    let default = StackReport::from_error(err).to_string();
+    assert!(default.lines().nth(2).unwrap().starts_with("  1| "));
+    assert!(default.lines().nth(3).unwrap().starts_with("  2| "));
+
+    let err = nested_error(2).unwrap_err();
+    let bracket = StackReport::from_error(err)
+        .index_style(IndexStyle::Bracket)
+        .to_string();
+    assert_eq!(
+        bracket,
+        default
+            .replace("  1| ", "  [1] ")
+            .replace("  2| ", "  [2] ")
+    );
+
+    let err = nested_error(2).unwrap_err();
+    let dot = StackReport::from_error(err).index_style(IndexStyle::Dot);
+    assert!(dot.to_string().contains("\n  2. DepthLeafError: leaf, at "));
+    assert!(!format!("{dot:#}").contains("2. "));
+}
+
+// --- hide_locations ---
+
+#[test]
+fn test_report_hide_locations() {
+    let shown = StackReport::from_error(save_error()).to_string();
+    assert_eq!(shown.matches(", at ").count(), 2);
+    assert!(shown.contains(file!()));
+
+    let hidden = StackReport::from_error(save_error()).hide_locations();
+    assert_eq!(
+        hidden.to_string(),
+        "Error: SaveError: save failed\n\
+         Caused by (recent first):\n  \
+         1| DiskFullError: disk full\n  \
+         2| no space left"
+    );
+    assert!(!format!("{hidden:#}").contains(file!()));
+}
+
+// --- hide_type_names ---
+
+#[test]
+fn test_report_hide_type_names() {
+    let err = save_error();
+    let save_loc = err.location();
+    let report = StackReport::from_error(err).hide_type_names();
+    let disk_loc = report
+        .links()
+        .find_map(|link| match link {
+            Link::StackFrame {
+                type_name: "DiskFullError",
+                location,
+                ..
+            } => Some(location),
+            _ => None,
+        })
+        .unwrap();
+    assert_eq!(
+        report.to_string(),
+        format!(
+            "Error: save failed, at {save_loc}\n\
+             Caused by (recent first):\n  \
+             1| disk full, at {disk_loc}\n  \
+             2| no space left"
+        )
+    );
+
+    let plain = StackReport::from_error(save_error())
+        .hide_type_names()
+        .hide_locations();
+    assert_eq!(
+        plain.to_string(),
+        "Error: save failed\n\
+         Caused by (recent first):\n  \
+         1| disk full\n  \
+         2| no space left"
+    );
+}
+
+// --- with_style ---
+
+/// Prints one type name per line; plain causes show their message.
+struct TypeNamesOnly;
+
+impl ReportStyle for TypeNamesOnly {
+    fn frame(
+        &self,
+        index: usize,
+        f: &mut std::fmt::Formatter<'_>,
+        frame: Link<'_>,
+    ) -> std::fmt::Result {
+        if index > 0 {
+            f.write_str("\n")?;
+        }
+        match frame {
+            Link::StackFrame { type_name, .. } => f.write_str(type_name),
+            Link::PlainFrame { error } => write!(f, "({error})"),
+            _ => Ok(()),
+        }
+    }
+
+    fn elision(
+        &self,
+        _index: usize,
+        f: &mut std::fmt::Formatter<'_>,
+        remaining: Option<usize>,
+    ) -> std::fmt::Result {
+        write!(f, "\n+{}", remaining.unwrap_or(0))
+    }
+}
+
+#[test]
+fn test_report_with_custom_style() {
+    let err = nested_error(2).unwrap_err();
+    let report = StackReport::from_error(err).with_style(TypeNamesOnly);
+    assert_eq!(
+        format!("{report}"),
+        "DepthLevelError\nDepthLevelError\nDepthLeafError"
+    );
+    assert_eq!(format!("{report:?}"), format!("{report}"));
+
+    let err = nested_error(3).unwrap_err();
+    let report = StackReport::from_error(err)
+        .max_depth(1)
+        .with_style(TypeNamesOnly);
+    assert_eq!(format!("{report}"), "DepthLevelError\nDepthLevelError\n+2");
+
+    let ok = StackReport::from_result(Ok::<(), TestReportError>(())).with_style(TypeNamesOnly);
+    assert_eq!(format!("{ok}"), "");
+}
+
+#[test]
+fn test_default_report_style_matches_builtin_output() {
+    for depth in [0, 1, 3] {
+        let plain = StackReport::from_error(nested_error(depth).unwrap_err()).max_depth(2);
+        let styled = StackReport::from_error(nested_error(depth).unwrap_err())
+            .max_depth(2)
+            .with_style(DefaultReportStyle);
+        // Both errors are built at the same sites, so the output is identical.
+        assert_eq!(format!("{styled}"), format!("{plain}"));
+        assert_eq!(format!("{styled:#}"), format!("{plain:#}"));
+    }
+}
+
+// --- into_report / from_result ---
+
+#[test]
+fn test_into_report() {
+    fn step(ok: bool) -> Result<(), TestReportError> {
+        ensure!(ok, TestReportSnafu { message: "step" });
+        Ok(())
+    }
+    assert_eq!(step(true).into_report().frame_count(), 0);
+
+    let output = step(false).into_report().to_string();
+    assert!(output.starts_with("Error: TestReportError: test error: step, at "));
+    // Same location: the error is created inside `step`
+    assert_eq!(output, StackReport::from_result(step(false)).to_string());
+}
+
+// --- frame_count ---
+
+#[test]
+fn test_report_frame_count() {
+    let ok: Result<(), BoxedStackError> = Ok(());
+    assert_eq!(StackReport::from(ok).frame_count(), 0);
+
+    let leaf = nested_error(0).unwrap_err();
+    assert_eq!(StackReport::from_error(leaf).frame_count(), 1);
+
+    let chain = nested_error(2).unwrap_err();
+    assert_eq!(StackReport::from_error(chain).frame_count(), 3);
+
+    // max_depth is ignored: the count is for the untruncated report.
+    let chain = nested_error(2).unwrap_err();
+    assert_eq!(StackReport::from_error(chain).max_depth(0).frame_count(), 3);
+
+    // Matches the printed frames: the transparent StoreError is left out.
+    let report = StackReport::from_error(save_error());
+    assert_eq!(report.links().count(), 4);
+    assert_eq!(report.frame_count(), 3);
+    assert_eq!(report.frame_count(), report.to_string().lines().count() - 1);
+
+    // Repeats dropped by dedup_adjacent are left out as well.
+    let report =
+        StackReport::from_error(Err::<(), _>(echo_chain()).context(UploadSnafu).unwrap_err());
+    assert_eq!(report.frame_count(), 4);
+    assert_eq!(report.dedup_adjacent().frame_count(), 2);
+
+    // Bounded like the report: the top-level error plus 128 causes.
+    let cyclic = CyclicError {
+        location: core::panic::Location::caller(),
+    };
+    assert_eq!(StackReport::from_error(&cyclic).frame_count(), 129);
+}
+
+// --- cyclic chains ---
+
+// A pathological error whose source is itself, in both phases.
+#[derive(Debug)]
+struct CyclicError {
+    location: Location,
+}
+
+impl core::fmt::Display for CyclicError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("cyclic")
+    }
+}
+
+impl std::error::Error for CyclicError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(self)
+    }
+}
+
+impl StackError for CyclicError {
+    fn location(&self) -> Location {
+        self.location
+    }
+    fn type_name(&self) -> &'static str {
+        "CyclicError"
+    }
+    fn stack_source(&self) -> Option<&dyn StackError> {
+        Some(self)
+    }
+}
+
+#[test]
+fn test_report_terminates_on_cycle() {
+    let err = CyclicError {
+        location: core::panic::Location::caller(),
+    };
+    let report = format!("{}", StackReport::from_error(&err));
+    let lines: Vec<&str> = report.lines().collect();
+    // Top line, `Caused by` header, 128 causes, and the marker
+    assert_eq!(lines.len(), 131, "{report}");
+    assert!(lines[129].contains("128| CyclicError: cyclic, at "));
+    assert_eq!(lines[130], "  ... (possible cycle)");
+}
+
+#[test]
+fn test_report_terminates_on_cycle_with_max_depth() {
+    let err = CyclicError {
+        location: core::panic::Location::caller(),
+    };
+    let report = format!("{}", StackReport::from_error(&err).max_depth(2));
+    let lines: Vec<&str> = report.lines().collect();
+    assert_eq!(lines.len(), 5, "{report}");
+    assert_eq!(lines[4], "  ... (possible cycle)");
+}
+
+// --- write_to / with_exit_context ---
+
+#[test]
+fn test_report_write_to_matches_display() {
+    let report = failure_case();
+    let mut out = Vec::new();
+    report.write_to(&mut out).unwrap();
+    assert_eq!(String::from_utf8(out).unwrap(), format!("{report}\n"));
+
+    let mut out = Vec::new();
+    success_case()
+        .with_exit_context()
+        .write_to(&mut out)
+        .unwrap();
+    assert!(out.is_empty());
+}
+
+#[test]
+fn test_report_with_exit_context() {
+    let out = std::thread::Builder::new()
+        .name("exporter".into())
+        .spawn(|| {
+            let mut out = Vec::new();
+            failure_case()
+                .with_exit_context()
+                .write_to(&mut out)
+                .unwrap();
+            String::from_utf8(out).unwrap()
+        })
+        .unwrap()
+        .join()
+        .unwrap();
+
+    let (context, report) = out.split_once('\n').unwrap();
+    // [2024-05-01T09:30:12.345Z thread 'exporter']
+    let timestamp = context
+        .strip_prefix('[')
+        .and_then(|rest| rest.strip_suffix(" thread 'exporter']"))
+        .unwrap();
+    assert_eq!(timestamp.len(), "2024-05-01T09:30:12.345Z".len());
+    for (i, c) in timestamp.char_indices() {
+        match i {
+            4 | 7 => assert_eq!(c, '-'),
+            10 => assert_eq!(c, 'T'),
+            13 | 16 => assert_eq!(c, ':'),
+            19 => assert_eq!(c, '.'),
+            23 => assert_eq!(c, 'Z'),
+            _ => assert!(c.is_ascii_digit(), "{timestamp}"),
+        }
+    }
+    assert_eq!(report, format!("{}\n", failure_case()));
+}
+
+#[test]
+fn test_styled_report_with_exit_context() {
+    let out = std::thread::Builder::new()
+        .name("exporter".into())
+        .spawn(|| {
+            let mut out = Vec::new();
+            StackReport::from_error(nested_error(1).unwrap_err())
+                .with_exit_context()
+                .with_style(TypeNamesOnly)
+                .write_to(&mut out)
+                .unwrap();
+            String::from_utf8(out).unwrap()
+        })
+        .unwrap()
+        .join()
+        .unwrap();
+
+    let (context, report) = out.split_once('\n').unwrap();
+    assert!(context.starts_with("[20"), "{context}");
+    assert!(context.ends_with("Z thread 'exporter']"), "{context}");
+    assert_eq!(report, "DepthLevelError\nDepthLeafError\n");
+
+    let mut out = Vec::new();
+    StackReport::from_result(Ok::<(), TestReportError>(()))
+        .with_exit_context()
+        .with_style(TypeNamesOnly)
+        .write_to(&mut out)
+        .unwrap();
+    assert!(out.is_empty());
+}
+
+// --- dedup_adjacent ---
+
+// Repeats its source's message, like some wrapper errors in the wild.
+#[derive(Debug)]
+struct EchoError(Box<dyn std::error::Error + Send + Sync>);
+
+impl std::fmt::Display for EchoError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl std::error::Error for EchoError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&*self.0)
+    }
+}
+
+#[suzunari_error]
+#[suzu(display("upload failed"))]
+struct UploadError {
+    source: EchoError,
+}
+
+#[suzunari_error]
+#[suzu(display("disk gone"))]
+struct DiskGoneError {
+    source: EchoError,
+}
+
+fn echo_chain() -> EchoError {
+    let io = std::io::Error::other("disk gone");
+    EchoError(Box::new(EchoError(Box::new(io))))
+}
+
+#[test]
+fn test_report_dedup_adjacent() {
+    let err = Err::<(), _>(echo_chain()).context(UploadSnafu).unwrap_err();
+    let loc = err.location();
+    let report = StackReport::from_error(err);
+    assert_eq!(
+        report.to_string(),
+        format!(
+            "Error: UploadError: upload failed, at {loc}\n\
+             Caused by (recent first):\n  \
+             1| disk gone\n  \
+             2| disk gone\n  \
+             3| disk gone"
+        )
+    );
+    assert_eq!(
+        report.dedup_adjacent().to_string(),
+        format!(
+            "Error: UploadError: upload failed, at {loc}\n\
+             Caused by (recent first):\n  \
+             1| disk gone"
+        )
+    );
+}
+
+#[test]
+fn test_report_dedup_adjacent_against_stack_frame() {
+    // A plain cause repeating the StackError above it is dropped as well.
+    let err = Err::<(), _>(echo_chain())
+        .context(DiskGoneSnafu)
+        .unwrap_err();
+    let loc = err.location();
+    assert_eq!(
+        StackReport::from_error(err).dedup_adjacent().to_string(),
+        format!("Error: DiskGoneError: disk gone, at {loc}")
+    );
+}
+
+#[test]
+fn test_report_dedup_adjacent_counts_elided_causes() {
+    let err = Err::<(), _>(echo_chain()).context(UploadSnafu).unwrap_err();
+    let report = StackReport::from_error(err).max_depth(0).dedup_adjacent();
+    assert_eq!(report.to_string().lines().count(), 1);
+
+    // disk gone -> upload failed -> (upload failed) -> disk gone -> (disk gone) -> (disk gone)
+    let inner = Err::<(), _>(echo_chain()).context(UploadSnafu).unwrap_err();
+    let err = Err::<(), _>(EchoError(Box::new(inner)))
+        .context(DiskGoneSnafu)
+        .unwrap_err();
+    let report = StackReport::from_error(err).max_depth(1).dedup_adjacent();
+    let lines: Vec<_> = report.to_string().lines().map(str::to_owned).collect();
+    assert_eq!(lines[2], "  1| upload failed");
+    assert_eq!(lines[3], "  ... (1 more)");
+    assert_eq!(lines.len(), 4);
+}
+
+// --- bare snafu errors ---
+
+// A bare snafu error has no location of its own, so it cannot be returned
+// from #[report] directly; `.stack_context(...)` or `BoxedStackError::msg`
+// locate it where it enters the report.
+#[derive(Debug, snafu::Snafu)]
+#[snafu(display("quota exceeded"))]
+struct BareQuotaError;
+
+#[derive(Debug, snafu::Snafu)]
+#[snafu(display("sync aborted"))]
+struct BareSyncError {
+    source: BareQuotaError,
+}
+
+fn bare_quota() -> Result<(), BareQuotaError> {
+    Err(BareQuotaError)
+}
+
+#[suzunari_error::report]
+fn located_bare_error() -> Result<(), BoxedStackError> {
+    bare_quota().stack_context(BareSyncSnafu)?;
+    Ok(())
+}
+
+#[suzunari_error::report]
+fn located_message() -> Result<(), BoxedStackError> {
+    Err(BoxedStackError::msg("no config"))
+}
+
+#[test]
+fn test_report_locates_bare_errors_at_entry() {
+    let report = located_bare_error();
+    let loc = report.as_result().unwrap_err().location();
+    assert_eq!(loc.file(), file!());
+    assert_eq!(
+        report.to_string(),
+        format!(
+            "Error: BareSyncError: sync aborted, at {loc}\n\
+             Caused by (recent first):\n  \
+             1| quota exceeded"
+        )
+    );
+
+    let report = located_message();
+    let loc = report.as_result().unwrap_err().location();
+    assert_eq!(loc.file(), file!());
+    assert_eq!(
+        report.to_string(),
+        format!("Error: Message: no config, at {loc}")
+    );
+}
+
+// --- MultiStackReport ---
+
+#[test]
+fn test_multi_stack_report() {
+    // .build() constructs each error at a known site, so every section's
+    // location can be asserted; .context() would capture it inside a helper.
+    let errors: Vec<TestReportError> = ["name", "email", "age"]
+        .into_iter()
+        .map(|message| TestReportSnafu { message }.build())
+        .collect();
+    let locs: Vec<_> = errors.iter().map(|e| e.location()).collect();
+    let report = MultiStackReport::from_errors(errors);
+    assert_eq!(report.len(), 3);
+    assert_eq!(
+        report.to_string(),
+        format!(
+            "3 errors occurred:\n\n\
+             [1] Error: TestReportError: test error: name, at {}\n\n\
+             [2] Error: TestReportError: test error: email, at {}\n\n\
+             [3] Error: TestReportError: test error: age, at {}",
+            locs[0], locs[1], locs[2]
+        )
+    );
+    assert_eq!(format!("{report:?}"), report.to_string());
+}
+
+#[test]
+fn test_multi_stack_report_sections_keep_causes() {
+    let mut report = MultiStackReport::new();
+    report.push(save_error());
+    report.push(save_error());
+    let output = report.to_string();
+    assert_eq!(output.matches("Caused by (recent first):").count(), 2);
+    assert_eq!(output.matches("  2| no space left").count(), 2);
+}
+
+#[test]
+fn test_multi_stack_report_empty() {
+    let report = MultiStackReport::<TestReportError>::new();
+    assert!(report.is_empty());
+    assert_eq!(report.to_string(), "");
+    assert!(report.into_result().is_ok());
+
+    let err = TestReportSnafu { message: "x" }.fail::<()>().unwrap_err();
+    let one = MultiStackReport::from_errors([err]);
+    assert!(
+        one.to_string()
+            .starts_with("1 error occurred:\n\n[1] Error: ")
+    );
+    assert_eq!(one.into_result().unwrap_err().errors().len(), 1);
+}
+
+// --- from_into ---
+
+#[test]
+fn test_report_from_into_boxed() {
+    // .build() captures the location here, so the rendered `{loc}` is known;
+    // .context() would capture it inside a helper.
+    let err = TestReportSnafu { message: "boxed" }.build();
+    let loc = err.location();
+    let report: StackReport<BoxedStackError> = StackReport::from_into(err);
+    assert_eq!(
+        report.as_result().unwrap_err().type_name(),
+        "TestReportError"
+    );
+    assert_eq!(
+        report.to_string(),
+        format!("Error: TestReportError: test error: boxed, at {loc}")
+    );
+}
@@ -150,3 +150,26 @@ fn test_error_propagation() {
     assert!(report.contains("2| NestedError"));
     assert!(report.contains("3| "));
 }
+
+#[test]
+fn test_has_stack_source_and_has_plain_source() {
+    let simple = SimpleSnafu.build();
+    assert!(!simple.has_stack_source());
+    assert!(!simple.has_plain_source());
+
+    // NestedError -> io::Error: plain source only.
+    let nested = function_c().unwrap_err();
+    assert!(!nested.has_stack_source());
+    assert!(nested.has_plain_source());
+
+    // Internal -> NestedError: StackError source.
+    let internal = function_c().context(InternalSnafu).unwrap_err();
+    assert!(internal.has_stack_source());
+    assert!(!internal.has_plain_source());
+
+    // External -> Box<dyn Error>: a StackError behind a plain Error type is
+    // still a plain source, as in the report.
+    let external = function_a().unwrap_err();
+    assert!(!external.has_stack_source());
+    assert!(external.has_plain_source());
+}
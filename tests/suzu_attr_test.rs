@@ -1,6 +1,6 @@
 #![cfg(feature = "std")]
 //! Integration tests for the `#[suzu(...)]` attribute.
-//! Tests verify `from`, `location`, and snafu passthrough behavior.
+//! Tests verify `from`, `location`, `meta`, and snafu passthrough behavior.
 //!
 //! `.build()` usage: These tests use `.build()` to construct errors at a known
 //! line number for location assertions. `.context()` would capture the wrong line.
@@ -159,6 +159,119 @@ fn test_from_non_source_named_field() {
 // #[suzu(from)] on generic type params is now rejected at compile time.
 // See tests/compile-fail/suzu_from_generic_type_param.rs
 
+// --- from(boxed): StackError sources boxed into BoxedStackError ---
+// The field is written with the source type and retyped to BoxedStackError,
+// so the source's own location stays in the stack trace.
+
+#[suzunari_error]
+#[suzu(display("inner failed"))]
+struct BoxedInnerError {}
+
+#[suzunari_error]
+enum BoxedOuterError {
+    #[suzu(display("load failed"))]
+    Load {
+        #[suzu(from(boxed))]
+        source: BoxedInnerError,
+    },
+    #[suzu(context(false), display("converted"))]
+    Converted {
+        #[suzu(from(boxed))]
+        source: FromStructError,
+    },
+}
+
+#[test]
+fn test_from_boxed_with_context() {
+    fn inner() -> Result<(), BoxedInnerError> {
+        BoxedInnerSnafu.fail()
+    }
+    fn outer() -> Result<(), BoxedOuterError> {
+        inner().context(LoadSnafu)?;
+        Ok(())
+    }
+    let err = outer().unwrap_err();
+    let BoxedOuterError::Load { source, .. } = &err else {
+        panic!("expected Load variant");
+    };
+    let _: &BoxedStackError = source;
+    assert_eq!(source.type_name(), "BoxedInnerError");
+    assert_eq!(err.depth(), 1);
+    let report = format!("{:?}", StackReport::from(err));
+    assert!(report.contains("BoxedOuterError::Load: load failed, at"));
+    assert!(report.contains("1| BoxedInnerError: inner failed, at"));
+}
+
+#[test]
+fn test_from_boxed_with_question_mark() {
+    fn inner() -> Result<(), FromStructError> {
+        Err(FakeLibError { message: "lib" }).context(FromStructSnafu)
+    }
+    fn outer() -> Result<(), BoxedOuterError> {
+        inner()?;
+        Ok(())
+    }
+    let err = outer().unwrap_err();
+    assert!(matches!(err, BoxedOuterError::Converted { .. }));
+    assert_eq!(err.stack_source().unwrap().type_name(), "FromStructError");
+}
+
+// --- from(T, with = f): custom conversion, no DisplayError wrapping ---
+// The field keeps its declared type; `f` converts the source type into it.
+
+#[derive(Debug)]
+struct LegacyStatus(i32);
+
+#[derive(Debug)]
+struct LegacyStatusError {
+    code: i32,
+}
+impl core::fmt::Display for LegacyStatusError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "legacy status {}", self.code)
+    }
+}
+impl std::error::Error for LegacyStatusError {}
+
+fn legacy_status_error(status: LegacyStatus) -> LegacyStatusError {
+    LegacyStatusError { code: status.0 }
+}
+
+#[suzunari_error]
+enum FromWithError {
+    #[suzu(display("legacy call failed"))]
+    Legacy {
+        #[suzu(from(LegacyStatus, with = legacy_status_error))]
+        source: LegacyStatusError,
+    },
+    #[suzu(display("boxed call failed"))]
+    Boxed {
+        #[suzu(from(BoxedInnerError, with = Box::new))]
+        source: Box<BoxedInnerError>,
+    },
+}
+
+#[test]
+fn test_from_with_custom_conversion() {
+    let err = Err::<(), _>(LegacyStatus(-7))
+        .context(LegacySnafu)
+        .unwrap_err();
+    let FromWithError::Legacy { source, .. } = &err else {
+        panic!("expected Legacy, got {err:?}");
+    };
+    assert_eq!(source.code, -7);
+    assert!(err.stack_source().is_none());
+    let report = format!("{}", StackReport::from_error(err));
+    assert!(report.starts_with("Error: FromWithError::Legacy: legacy call failed, at "));
+    assert!(report.ends_with("\n  1| legacy status -7"));
+
+    // A StackError target keeps its location in the chain.
+    let err = Err::<(), _>(BoxedInnerSnafu.build())
+        .context(BoxedSnafu)
+        .unwrap_err();
+    assert_eq!(err.stack_source().unwrap().type_name(), "BoxedInnerError");
+}
+
 // --- location: explicit #[suzu(location)] ---
 
 #[suzunari_error]
@@ -203,6 +316,166 @@ fn test_mixed_location_enum() {
     assert!(err.location().file().ends_with("suzu_attr_test.rs"));
 }
 
+// --- location: custom type via AsLocation ---
+
+// A team-specific location newtype: captured implicitly through
+// GenerateImplicitData and exposed as a Location through Deref.
+#[derive(Debug, Clone, Copy)]
+struct SiteLocation(Location);
+
+impl std::ops::Deref for SiteLocation {
+    type Target = Location;
+    fn deref(&self) -> &Location {
+        &self.0
+    }
+}
+
+impl snafu::GenerateImplicitData for SiteLocation {
+    #[track_caller]
+    fn generate() -> Self {
+        SiteLocation(std::panic::Location::caller())
+    }
+}
+
+impl AsLocation for SiteLocation {
+    fn as_location(&self) -> Location {
+        **self
+    }
+}
+
+#[suzunari_error]
+#[suzu(display("custom location"))]
+struct CustomLocationError {
+    #[suzu(location)]
+    site: SiteLocation,
+}
+
+#[suzunari_error]
+enum CustomLocationEnum {
+    #[suzu(display("custom variant"))]
+    Custom {
+        #[suzu(location)]
+        site: SiteLocation,
+    },
+    #[suzu(display("builtin variant"))]
+    Builtin,
+}
+
+#[test]
+fn test_custom_location_type() {
+    let err = CustomLocationSnafu.build();
+    assert_eq!(err.location(), *err.site);
+    assert!(err.location().file().ends_with("suzu_attr_test.rs"));
+
+    let err = CustomSnafu.build();
+    let CustomLocationEnum::Custom { site, .. } = &err else {
+        panic!("expected Custom variant");
+    };
+    assert_eq!(err.location(), **site);
+
+    let err = BuiltinSnafu.build();
+    assert!(err.location().file().ends_with("suzu_attr_test.rs"));
+}
+
+// --- location: SourceSpan as the location field ---
+
+#[suzunari_error]
+#[suzu(display("spanned"))]
+struct SpannedError {
+    #[suzu(location)]
+    span: SourceSpan,
+}
+
+#[test]
+fn test_source_span_location_field() {
+    let err = SpannedSnafu.build();
+    let line = line!() - 1;
+    assert_eq!(err.location(), err.span.start());
+    assert_eq!(err.location().line(), line);
+    assert_eq!(err.span.end_line(), None);
+
+    let span = err.span.with_end(line + 1, 4);
+    assert_eq!(span.end_line(), Some(line + 1));
+    assert_eq!(span.end_column(), Some(4));
+}
+
+// --- location: referenced from display(...) ---
+
+// The injected field is an ordinary snafu field, so `{location}` works in
+// `display(...)` like any other field; a custom-named one uses its own name.
+#[suzunari_error]
+enum LocatedMessageError {
+    #[suzu(display("lookup failed at {location}"))]
+    Lookup,
+    #[suzu(display("region failed at {span}"))]
+    Region {
+        #[suzu(location)]
+        span: SourceSpan,
+    },
+}
+
+#[test]
+fn test_display_references_location() {
+    let err = LookupSnafu.build();
+    let line = line!() - 1;
+    assert_eq!(err.location().line(), line);
+    assert_eq!(
+        err.to_string(),
+        format!("lookup failed at {}", err.location())
+    );
+
+    let err = RegionSnafu.build();
+    let LocatedMessageError::Region { span } = &err else {
+        panic!("expected Region, got {err:?}");
+    };
+    assert_eq!(err.to_string(), format!("region failed at {span}"));
+}
+
+// --- skip_location: location taken from the source ---
+
+#[suzunari_error]
+#[suzu(display("query failed"))]
+struct QueryError {}
+
+// Converted by `?` without context: the frame would only repeat the
+// conversion site, so it reports where the source was created instead.
+#[suzunari_error]
+#[suzu(skip_location, context(false), display("storage failed"))]
+struct StorageError {
+    source: QueryError,
+}
+
+#[suzunari_error]
+enum ServiceError {
+    #[suzu(skip_location, context(false))]
+    Storage { source: StorageError },
+    #[suzu(display("timed out"))]
+    Timeout,
+}
+
+const QUERY_LINE: u32 = line!() + 3;
+
+fn run_query() -> Result<(), ServiceError> {
+    let query = QuerySnafu.build();
+    // Without skip_location, both frames would be located here.
+    Err(StorageError::from(query))?;
+    Ok(())
+}
+
+#[test]
+fn test_skip_location_defers_to_source() {
+    let err = run_query().unwrap_err();
+    let query = err.find::<QueryError>().unwrap().location();
+    assert_eq!(query.line(), QUERY_LINE);
+    assert_eq!(err.location(), query);
+    assert_eq!(err.stack_source().unwrap().location(), query);
+    assert_eq!(err.type_name(), "ServiceError::Storage");
+
+    // Other variants still get an injected location.
+    let timeout = TimeoutSnafu.build();
+    assert_eq!(timeout.location().line(), line!() - 1);
+}
+
 // --- snafu passthrough only (no suzunari extensions) ---
 
 #[suzunari_error]
@@ -221,6 +494,53 @@ fn test_passthrough_only() {
     assert!(report.contains("pass through"));
 }
 
+// --- display: placeholders checked against fields and named arguments ---
+
+const MAX_RETRIES: u32 = 3;
+
+#[suzunari_error]
+enum PlaceholderError {
+    #[suzu(display("{{literal}} {attempt:>width$}/{max} {}", kind, max = MAX_RETRIES))]
+    Retry {
+        attempt: u32,
+        width: usize,
+        kind: &'static str,
+    },
+    #[suzu(display("{kind} at line {}", location.line()))]
+    Located { kind: &'static str },
+}
+
+#[test]
+fn test_display_placeholders_accept_fields_and_named_args() {
+    let err = RetrySnafu {
+        attempt: 2u32,
+        width: 3usize,
+        kind: "fetch",
+    }
+    .build();
+    assert_eq!(err.to_string(), "{literal}   2/3 fetch");
+
+    let err = LocatedSnafu { kind: "scan" }.build();
+    assert_eq!(
+        err.to_string(),
+        format!("scan at line {}", err.location().line())
+    );
+}
+
+const LIMIT: u32 = 10;
+
+#[suzunari_error]
+#[suzu(display("over {LIMIT}: {n}"))]
+struct OverLimitError {
+    n: u32,
+}
+
+#[test]
+fn test_display_placeholders_capture_inline_consts() {
+    let err = OverLimitSnafu { n: 3u32 }.build();
+    assert_eq!(err.to_string(), "over 10: 3");
+}
+
 // --- mixed: suzu(display(...)) on variant + suzu(from) on field ---
 
 #[suzunari_error]
@@ -342,6 +662,33 @@ fn test_stack_report_with_from_chain() {
     assert_eq!(lines.len(), 4);
 }
 
+#[test]
+fn test_stack_depth_and_total_depth_with_from_chain() {
+    fn fake_hash() -> Result<(), FakeLibError> {
+        Err(FakeLibError {
+            message: "hash fail",
+        })
+    }
+    let inner = fake_hash().context(HashFailedSnafu).unwrap_err();
+    assert_eq!(inner.stack_depth(), 0);
+    assert_eq!(inner.total_depth(), 1);
+
+    let err = Err::<(), _>(inner).context(OuterSnafu).unwrap_err();
+    // OuterError -> FromEnumError::HashFailed: one stack_source() hop,
+    // then DisplayError<FakeLibError> as the plain source() tail.
+    assert_eq!(err.stack_depth(), 1);
+    assert_eq!(err.total_depth(), 2);
+    assert_eq!(err.total_depth(), err.depth());
+
+    let report = StackReport::from(err);
+    assert_eq!(report.frame_count(), 3);
+    let located = report
+        .links()
+        .filter(|link| matches!(link, Link::StackFrame { .. }))
+        .count();
+    assert_eq!(located, 1 + report.as_result().unwrap_err().stack_depth());
+}
+
 // --- from: source chain preservation for Error-implementing types ---
 // When the inner type implements Error, #[suzu(from)] should preserve the
 // source chain via autoref specialization. DisplayError::source() delegates
@@ -450,3 +797,315 @@ fn test_closure_syntax_source() {
     assert!(report.contains("closure source error"));
     assert!(report.contains("closure test"));
 }
+
+// --- meta: key-value metadata per frame ---
+
+#[suzunari_error]
+enum MetaEnumError {
+    #[suzu(display("request failed"))]
+    RequestFailed {
+        #[suzu(meta)]
+        user_id: u32,
+        #[suzu(meta)]
+        request_id: String,
+        detail: String,
+    },
+    #[suzu(display("no metadata"))]
+    Plain,
+}
+
+#[suzunari_error]
+#[suzu(display("handler failed"))]
+struct MetaWrapperError {
+    #[suzu(meta)]
+    route: &'static str,
+    source: MetaEnumError,
+}
+
+fn collect_metadata(err: &dyn StackError) -> Vec<String> {
+    let mut entries = Vec::new();
+    err.metadata(&mut |key, value| entries.push(format!("{key}={value}")));
+    entries
+}
+
+#[test]
+fn test_meta_fields_in_declaration_order() {
+    let err = RequestFailedSnafu {
+        user_id: 7u32,
+        request_id: "abc",
+        detail: "ignored",
+    }
+    .build();
+    assert_eq!(collect_metadata(&err), ["user_id=7", "request_id=abc"]);
+
+    let plain = PlainSnafu.build();
+    assert!(collect_metadata(&plain).is_empty());
+}
+
+#[test]
+fn test_meta_rendered_per_frame_in_report() {
+    fn request() -> Result<(), MetaEnumError> {
+        ensure!(
+            false,
+            RequestFailedSnafu {
+                user_id: 7u32,
+                request_id: "abc",
+                detail: "not shown",
+            }
+        );
+        Ok(())
+    }
+    let err = request()
+        .context(MetaWrapperSnafu { route: "/users" })
+        .unwrap_err();
+    let report = format!("{}", StackReport::from(err));
+    let lines: Vec<&str> = report.lines().collect();
+    assert!(lines[0].ends_with(" (route=/users)"), "{report}");
+    assert!(lines[2].contains("1| MetaEnumError::RequestFailed: request failed, at "));
+    assert!(
+        lines[2].ends_with(" (user_id=7, request_id=abc)"),
+        "{report}"
+    );
+    assert!(!report.contains("not shown"));
+}
+
+#[test]
+fn test_meta_preserved_through_boxed_stack_error() {
+    let err = BoxedStackError::new(
+        RequestFailedSnafu {
+            user_id: 1u32,
+            request_id: "boxed",
+            detail: "",
+        }
+        .build(),
+    );
+    assert_eq!(collect_metadata(&err), ["user_id=1", "request_id=boxed"]);
+}
+
+// --- context(false): location captured at the `?` conversion ---
+
+#[suzunari_error]
+#[suzu(display("parse step failed"))]
+struct ParseStepError {}
+
+#[suzunari_error]
+enum ConvertedError {
+    #[suzu(context(false), display("implicitly converted"))]
+    Implicit { source: ParseStepError },
+}
+
+#[test]
+fn test_context_false_location_is_conversion_point() {
+    fn parse() -> Result<(), ParseStepError> {
+        ParseStepSnafu.fail()
+    }
+    let question_mark_line = line!() + 2;
+    fn run() -> Result<(), ConvertedError> {
+        parse()?;
+        Ok(())
+    }
+    let err = run().unwrap_err();
+    assert!(matches!(err, ConvertedError::Implicit { .. }));
+    assert!(err.location().file().ends_with("suzu_attr_test.rs"));
+    assert_eq!(err.location().line(), question_mark_line);
+    assert_ne!(err.location(), err.stack_source().unwrap().location());
+}
+
+// --- rename ---
+
+#[suzunari_error]
+#[suzu(rename = "DiskFull", display("disk is full"))]
+struct StorageFullError {}
+
+#[suzunari_error]
+enum VolumeError {
+    #[suzu(rename = "Missing", display("volume not found"))]
+    NotFound,
+    #[suzu(rename(full = "ReadOnly"), display("volume is read-only"))]
+    ReadOnlyVolume,
+    #[suzu(display("volume is busy"))]
+    Busy,
+}
+
+#[suzunari_error]
+#[suzu(rename = "Volume")]
+enum RenamedVolumeError {
+    #[suzu(rename = "Gone", display("volume gone"))]
+    Removed,
+    #[suzu(display("volume locked"))]
+    Locked,
+}
+
+#[test]
+fn test_rename_struct() {
+    let err = StorageFullSnafu.build();
+    assert_eq!(err.type_name(), "DiskFull");
+    let report = format!("{}", StackReport::from_error(err));
+    assert!(report.starts_with("Error: DiskFull: disk is full, at "));
+}
+
+#[test]
+fn test_rename_variant_replaces_variant_portion() {
+    assert_eq!(NotFoundSnafu.build().type_name(), "VolumeError::Missing");
+    assert_eq!(RemovedSnafu.build().type_name(), "Volume::Gone");
+}
+
+#[test]
+fn test_rename_full_overrides_enum_name() {
+    assert_eq!(ReadOnlyVolumeSnafu.build().type_name(), "ReadOnly");
+}
+
+#[test]
+fn test_rename_default_and_type_level() {
+    assert_eq!(BusySnafu.build().type_name(), "VolumeError::Busy");
+    assert_eq!(LockedSnafu.build().type_name(), "Volume::Locked");
+}
+
+// --- severity ---
+
+#[suzunari_error]
+#[suzu(severity = warning)]
+enum QuotaError {
+    #[suzu(display("quota nearly exhausted"))]
+    NearLimit,
+    #[suzu(severity = critical, display("quota store unreachable"))]
+    StoreDown,
+    #[suzu(severity = info, display("quota refreshed"))]
+    Refreshed,
+}
+
+#[suzunari_error]
+enum MixedSeverityError {
+    #[suzu(severity = critical, display("fatal"))]
+    Fatal,
+    #[suzu(display("ordinary"))]
+    Ordinary,
+}
+
+#[suzunari_error]
+#[suzu(severity = critical, display("out of memory"))]
+struct OutOfMemoryError {}
+
+#[test]
+fn test_severity_variant_overrides_type_level() {
+    assert_eq!(NearLimitSnafu.build().severity(), Severity::Warning);
+    assert_eq!(StoreDownSnafu.build().severity(), Severity::Critical);
+    assert_eq!(RefreshedSnafu.build().severity(), Severity::Info);
+}
+
+#[test]
+fn test_severity_default() {
+    assert_eq!(StorageFullSnafu.build().severity(), Severity::Error);
+    assert_eq!(FatalSnafu.build().severity(), Severity::Critical);
+    assert_eq!(OrdinarySnafu.build().severity(), Severity::Error);
+    assert_eq!(Severity::default(), Severity::Error);
+}
+
+#[test]
+fn test_severity_struct_and_delegation() {
+    let err = OutOfMemorySnafu.build();
+    assert_eq!(err.severity(), Severity::Critical);
+    assert!(err.severity() > Severity::Error);
+    assert_eq!(BoxedStackError::new(err).severity(), Severity::Critical);
+}
+
+// --- single-field tuple variants ---
+
+#[suzunari_error]
+#[suzu(display("parse failed"))]
+struct TupleParseError {}
+
+#[suzunari_error]
+enum TupleVariantError {
+    // Becomes `Lib { source: DisplayError<FakeLibError>, location }`
+    #[suzu(display("lib failed"))]
+    Lib(#[suzu(from)] FakeLibError),
+    // Becomes `Parse { source: TupleParseError, location }`
+    #[suzu(display("parse step failed"))]
+    Parse(TupleParseError),
+    #[suzu(display("no source"))]
+    Sourceless,
+}
+
+#[test]
+fn test_tuple_variant_from() {
+    let err = Err::<(), _>(FakeLibError {
+        message: "lib boom",
+    })
+    .context(LibSnafu)
+    .unwrap_err();
+    assert_eq!(err.type_name(), "TupleVariantError::Lib");
+    let TupleVariantError::Lib { source, .. } = &err else {
+        panic!("expected Lib, got {err:?}");
+    };
+    assert_eq!(source.to_string(), "lib boom");
+    assert!(err.location().file().ends_with("suzu_attr_test.rs"));
+    // DisplayError is not a StackError
+    assert!(err.stack_source().is_none());
+}
+
+#[test]
+fn test_tuple_variant_stack_source() {
+    let err = Err::<(), _>(TupleParseSnafu.build())
+        .context(ParseSnafu)
+        .unwrap_err();
+    assert_eq!(err.stack_source().unwrap().type_name(), "TupleParseError");
+    let report = format!("{}", StackReport::from_error(err));
+    assert!(report.contains("1| TupleParseError: parse failed, at "));
+    assert!(SourcelessSnafu.build().stack_source().is_none());
+}
+
+// --- snafu selector options: context(suffix(...)) and module ---
+
+#[suzunari_error]
+enum SelectorSuffixError {
+    #[suzu(context(suffix(Error)), display("key `{key}` missing"))]
+    KeyMissing { key: String },
+    #[suzu(context(suffix(false)), display("store sealed"))]
+    StoreSealed,
+}
+
+#[test]
+fn test_context_suffix_renames_selectors() {
+    let err = KeyMissingError { key: "port" }.build();
+    assert_eq!(err.type_name(), "SelectorSuffixError::KeyMissing");
+    assert!(err.location().file().ends_with("suzu_attr_test.rs"));
+    let report = format!("{}", StackReport::from_error(err));
+    assert!(report.starts_with("Error: SelectorSuffixError::KeyMissing: key `port` missing, at "));
+
+    let sealed = StoreSealed.build();
+    assert!(matches!(sealed, SelectorSuffixError::StoreSealed { .. }));
+}
+
+#[suzunari_error]
+#[suzu(display("leaf"))]
+struct ModuleSelectorLeafError {}
+
+#[suzunari_error]
+#[suzu(module)]
+enum ModuleSelectorError {
+    #[suzu(display("disk offline"))]
+    Offline,
+    #[suzu(display("retry {attempt} failed"))]
+    Retry {
+        attempt: u32,
+        source: ModuleSelectorLeafError,
+    },
+}
+
+#[test]
+fn test_module_selectors() {
+    use module_selector_error::{OfflineSnafu, RetrySnafu};
+
+    let offline = OfflineSnafu.build();
+    assert!(matches!(offline, ModuleSelectorError::Offline { .. }));
+    assert!(offline.location().file().ends_with("suzu_attr_test.rs"));
+
+    let err = Err::<(), _>(ModuleSelectorLeafSnafu.build())
+        .context(RetrySnafu { attempt: 2u32 })
+        .unwrap_err();
+    assert_eq!(err.type_name(), "ModuleSelectorError::Retry");
+    let report = format!("{}", StackReport::from_error(err));
+    assert!(report.starts_with("Error: ModuleSelectorError::Retry: retry 2 failed, at "));
+    assert!(report.contains("\n  1| ModuleSelectorLeafError: leaf, at "));
+}